@@ -0,0 +1,5 @@
+SELECT
+    COUNT(*) AS member_count,
+    COALESCE(SUM(CASE WHEN TRIM(wishlist) = '' THEN 1 ELSE 0 END), 0) AS empty_wishlist_count
+FROM members
+WHERE pool_id = '{{pool_id}}';
\ No newline at end of file
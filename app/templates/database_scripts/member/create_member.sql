@@ -1,2 +1,2 @@
-INSERT INTO members (id, account_id, room_id, pool_id, wishlist)
-VALUES ('{{id}}', '{{account_id}}', '{{room_id}}', '{{pool_id}}', '{{wishlist}}');
\ No newline at end of file
+INSERT INTO members (id, account_id, pool_id, wishlist, group_name)
+VALUES ('{{id}}', '{{account_id}}', '{{pool_id}}', '{{wishlist}}', '{{group_name}}');
\ No newline at end of file
@@ -1,4 +1,4 @@
-SELECT id, account_id, room_id, pool_id, wishlist 
+SELECT id, account_id, pool_id, wishlist, group_name 
 FROM members
 WHERE
     account_id = '{{account_id}}';
\ No newline at end of file
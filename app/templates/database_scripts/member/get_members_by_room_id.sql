@@ -1,4 +0,0 @@
-SELECT id, account_id, room_id, pool_id, wishlist 
-FROM members
-WHERE
-    room_id = '{{room_id}}';
\ No newline at end of file
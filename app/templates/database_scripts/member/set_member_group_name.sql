@@ -0,0 +1,3 @@
+UPDATE members
+    SET group_name = '{{group_name}}'
+WHERE id = '{{id}}';
\ No newline at end of file
@@ -1,3 +0,0 @@
-UPDATE members
-    SET room_id = '{{room_id}}'
-WHERE id = '{{id}}';
\ No newline at end of file
@@ -0,0 +1,5 @@
+SELECT id, pool_id, account_id, encrypted_address, last_update_date
+FROM member_addresses
+WHERE
+    pool_id = '{{pool_id}}' AND
+    account_id = '{{account_id}}';
\ No newline at end of file
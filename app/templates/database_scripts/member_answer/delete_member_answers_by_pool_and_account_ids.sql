@@ -0,0 +1,4 @@
+DELETE FROM member_answers
+WHERE
+    pool_id = '{{pool_id}}' AND
+    account_id = '{{account_id}}';
\ No newline at end of file
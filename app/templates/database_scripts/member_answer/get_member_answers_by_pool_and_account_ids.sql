@@ -0,0 +1,5 @@
+SELECT id, pool_id, account_id, question_id, text_content
+FROM member_answers
+WHERE
+    pool_id = '{{pool_id}}' AND
+    account_id = '{{account_id}}';
\ No newline at end of file
@@ -0,0 +1,5 @@
+SELECT DATE(creation_date) AS day, COUNT(*) AS message_count
+FROM messages
+WHERE pool_id = '{{pool_id}}'
+GROUP BY DATE(creation_date)
+ORDER BY day;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE {{table_name}} ADD COLUMN {{column_name}} {{column_definition | safe}};
\ No newline at end of file
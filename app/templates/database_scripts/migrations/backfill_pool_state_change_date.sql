@@ -0,0 +1,3 @@
+UPDATE pools
+    SET state_change_date = creation_date
+WHERE state_change_date = '';
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE {{table_name}} DROP COLUMN {{column_name}};
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT EXISTS(SELECT 1 FROM pragma_table_info('{{table_name}}') WHERE name = '{{column_name}}') AS row_exists;
\ No newline at end of file
@@ -1,2 +1,2 @@
-INSERT INTO pools (id, name, description, account_id, min_price, max_price, lifetime, creation_date, pool_state)
-VALUES ('{{id}}', '{{name}}', '{{description}}', '{{account_id}}', {{min_price}}, {{max_price}}, {{lifetime}}, '{{creation_date}}', {{pool_state}});
\ No newline at end of file
+INSERT INTO pools (id, name, description, account_id, min_price, max_price, lifetime, creation_date, pool_state, previous_pool_id, history_depth, gifts_per_member, cross_group_draw, end_room_state, open_at, draw_at, end_at, visibility, max_members, currency, archive_date, state_change_date)
+VALUES ('{{id}}', '{{name}}', '{{description}}', '{{account_id}}', {{min_price}}, {{max_price}}, {{lifetime}}, '{{creation_date}}', {{pool_state}}, '{{previous_pool_id}}', {{history_depth}}, {{gifts_per_member}}, '{{cross_group_draw}}', {{end_room_state}}, '{{open_at}}', '{{draw_at}}', '{{end_at}}', {{visibility}}, {{max_members}}, '{{currency}}', '{{archive_date}}', '{{state_change_date}}');
\ No newline at end of file
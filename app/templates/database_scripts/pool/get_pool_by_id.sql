@@ -1,6 +1,7 @@
 SELECT id, name, description, account_id,
 min_price, max_price, 
-lifetime, creation_date, pool_state
+lifetime, creation_date, pool_state,
+previous_pool_id, history_depth, gifts_per_member, cross_group_draw, end_room_state, open_at, draw_at, end_at, visibility, max_members, currency, archive_date, state_change_date
 FROM pools
 WHERE
     id = '{{id}}';
\ No newline at end of file
@@ -0,0 +1,3 @@
+UPDATE pool_join_tokens
+    SET uses_count = uses_count - 1
+WHERE id = '{{id}}' AND uses_count > 0;
\ No newline at end of file
@@ -0,0 +1,4 @@
+UPDATE pool_join_tokens
+    SET uses_count = uses_count + 1
+WHERE id = '{{id}}' AND is_revoked = 'false' AND (max_uses = 0 OR uses_count < max_uses)
+RETURNING uses_count;
\ No newline at end of file
@@ -0,0 +1,5 @@
+SELECT id, pool_id, account_id, creation_date
+FROM pool_organizers
+WHERE
+    pool_id = '{{pool_id}}' AND
+    account_id = '{{account_id}}';
\ No newline at end of file
@@ -0,0 +1,5 @@
+SELECT room_state, COUNT(*) AS room_count
+FROM rooms
+WHERE pool_id = '{{pool_id}}'
+GROUP BY room_state
+ORDER BY room_state;
\ No newline at end of file
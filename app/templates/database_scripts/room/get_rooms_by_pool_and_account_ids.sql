@@ -0,0 +1,6 @@
+SELECT 
+    id, pool_id, mailer_id, recipient_id, room_state
+FROM rooms
+WHERE
+    pool_id = '{{pool_id}}' AND
+    (mailer_id = '{{account_id}}' OR recipient_id = '{{account_id}}');
\ No newline at end of file
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS draw_proofs (
+    id VARCHAR(36) PRIMARY KEY,
+    pool_id VARCHAR(36) NOT NULL,
+    seed VARCHAR(256) NOT NULL,
+    commitment VARCHAR(64) NOT NULL,
+    members TEXT NOT NULL,
+    forbidden_pairs TEXT NOT NULL,
+    history_pairs TEXT NOT NULL,
+    pairs TEXT NOT NULL,
+    creation_date DATE NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS exclusions (
+    id VARCHAR(36) PRIMARY KEY,
+    account_id VARCHAR(36) NOT NULL,
+    pool_id VARCHAR(36) NOT NULL,
+    first_account_id VARCHAR(36) NOT NULL,
+    second_account_id VARCHAR(36) NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS gift_spends (
+    id VARCHAR(36) PRIMARY KEY,
+    room_id VARCHAR(36) NOT NULL UNIQUE,
+    pool_id VARCHAR(36) NOT NULL,
+    amount INTEGER NOT NULL,
+    currency VARCHAR(3) NOT NULL,
+    creation_date DATE NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS member_addresses (
+    id VARCHAR(36) PRIMARY KEY,
+    pool_id VARCHAR(36) NOT NULL,
+    account_id VARCHAR(36) NOT NULL,
+    encrypted_address TEXT NOT NULL,
+    last_update_date DATE NOT NULL,
+    UNIQUE(pool_id, account_id)
+);
\ No newline at end of file
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS member_answers (
+    id VARCHAR(36) PRIMARY KEY,
+    pool_id VARCHAR(36) NOT NULL,
+    account_id VARCHAR(36) NOT NULL,
+    question_id VARCHAR(36) NOT NULL,
+    text_content TEXT NOT NULL,
+    UNIQUE(account_id, question_id)
+);
\ No newline at end of file
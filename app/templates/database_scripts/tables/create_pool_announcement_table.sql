@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS pool_announcements (
+    id VARCHAR(36) PRIMARY KEY,
+    pool_id VARCHAR(36) NOT NULL,
+    account_id VARCHAR(36) NOT NULL,
+    text_content TEXT NOT NULL,
+    is_pinned BOOLEAN NOT NULL,
+    creation_date DATE NOT NULL,
+    last_update_date DATE NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS pool_join_tokens (
+    id VARCHAR(36) PRIMARY KEY,
+    pool_id VARCHAR(36) NOT NULL,
+    token VARCHAR(128) NOT NULL UNIQUE,
+    max_uses INTEGER NOT NULL,
+    uses_count INTEGER NOT NULL,
+    is_revoked BOOLEAN NOT NULL,
+    creation_date DATE NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS pool_organizers (
+    id VARCHAR(36) PRIMARY KEY,
+    pool_id VARCHAR(36) NOT NULL,
+    account_id VARCHAR(36) NOT NULL,
+    creation_date DATE NOT NULL,
+    UNIQUE(pool_id, account_id)
+);
\ No newline at end of file
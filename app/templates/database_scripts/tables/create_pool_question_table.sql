@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS pool_questions (
+    id VARCHAR(36) PRIMARY KEY,
+    pool_id VARCHAR(36) NOT NULL,
+    text_content TEXT NOT NULL,
+    is_required BOOLEAN NOT NULL,
+    position INTEGER NOT NULL
+);
\ No newline at end of file
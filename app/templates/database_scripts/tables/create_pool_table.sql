@@ -7,5 +7,18 @@ CREATE TABLE IF NOT EXISTS pools (
     max_price INTEGER NOT NULL,
     creation_date DATE NOT NULL,
     lifetime INTEGER NOT NULL,
-    pool_state INTEGER NOT NULL
+    pool_state INTEGER NOT NULL,
+    previous_pool_id VARCHAR(36) NOT NULL,
+    history_depth INTEGER NOT NULL,
+    gifts_per_member INTEGER NOT NULL,
+    cross_group_draw BOOLEAN NOT NULL,
+    end_room_state INTEGER NOT NULL,
+    open_at VARCHAR(64) NOT NULL,
+    draw_at VARCHAR(64) NOT NULL,
+    end_at VARCHAR(64) NOT NULL,
+    visibility INTEGER NOT NULL,
+    max_members INTEGER NOT NULL,
+    currency VARCHAR(3) NOT NULL,
+    archive_date VARCHAR(64) NOT NULL,
+    state_change_date VARCHAR(64) NOT NULL
 );
\ No newline at end of file
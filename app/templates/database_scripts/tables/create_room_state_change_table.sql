@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS room_state_changes (
+    id VARCHAR(36) PRIMARY KEY,
+    room_id VARCHAR(36) NOT NULL,
+    pool_id VARCHAR(36) NOT NULL,
+    account_id VARCHAR(36) NOT NULL,
+    room_state INTEGER NOT NULL,
+    creation_date DATE NOT NULL
+);
\ No newline at end of file
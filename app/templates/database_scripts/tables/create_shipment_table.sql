@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS shipments (
+    id VARCHAR(36) PRIMARY KEY,
+    room_id VARCHAR(36) NOT NULL UNIQUE,
+    pool_id VARCHAR(36) NOT NULL,
+    carrier_name VARCHAR(256) NOT NULL,
+    tracking_number VARCHAR(128) NOT NULL,
+    expected_delivery_date VARCHAR(10) NOT NULL,
+    last_update_date DATE NOT NULL
+);
\ No newline at end of file
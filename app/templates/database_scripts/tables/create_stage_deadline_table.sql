@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS stage_deadlines (
+    id VARCHAR(36) PRIMARY KEY,
+    pool_id VARCHAR(36) NOT NULL,
+    room_state INTEGER NOT NULL,
+    deadline DATE NOT NULL,
+    UNIQUE(pool_id, room_state)
+);
\ No newline at end of file
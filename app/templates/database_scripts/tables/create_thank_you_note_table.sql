@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS thank_you_notes (
+    id VARCHAR(36) PRIMARY KEY,
+    room_id VARCHAR(36) NOT NULL UNIQUE,
+    pool_id VARCHAR(36) NOT NULL,
+    text_content TEXT NOT NULL,
+    photo_type VARCHAR(32) NOT NULL,
+    photo TEXT NOT NULL,
+    creation_date DATE NOT NULL
+);
\ No newline at end of file
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS waitlist_entries (
+    id VARCHAR(36) PRIMARY KEY,
+    pool_id VARCHAR(36) NOT NULL,
+    account_id VARCHAR(36) NOT NULL,
+    wishlist TEXT NOT NULL,
+    creation_date DATE NOT NULL,
+    UNIQUE(pool_id, account_id)
+);
\ No newline at end of file
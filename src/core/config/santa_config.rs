@@ -6,5 +6,9 @@ pub struct SantaConfig {
     pub pool_lifetime_check_freq : u64,
     pub message_lifetime : u64,
     pub max_messages_in_room_count : u64,
-    pub old_messages_check_freq : u64
+    pub old_messages_check_freq : u64,
+    pub finished_pools_check_freq : u64,
+    pub scheduled_pools_check_freq : u64,
+    pub default_currency : String,
+    pub archived_pool_retention : u64
 }
\ No newline at end of file
@@ -98,6 +98,15 @@ pub async fn get_many_items_from_command<T>(command : &str, state : &AppState, t
     }
 }
 
+// for aggregate queries whose rows are not local objects
+pub async fn get_many_rows_from_command<T>(command : &str, state : &AppState, transform_func : fn(&SqliteRow) -> T) -> Option<Vec<T>> {
+    let conn = state.db.lock().await;
+    return match conn.fetch_all(command).await {
+        Ok(o) => Some(o.iter().map(transform_func).collect()),
+        Err(_) => None
+    };
+}
+
 pub async fn command_result_exists(command : &str, state : &AppState) -> bool {
     let conn = state.db.lock().await;
     let result = conn.fetch_one(command).await.unwrap();
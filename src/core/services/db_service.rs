@@ -18,6 +18,20 @@ pub trait IDbService {
 
     fn new(state : &AppState) -> Self;
 
+    fn insert_query_unsafe(&self, table_name : &str, props : Vec<&str>, values : Vec<Vec<String>>) -> String;
+
+    fn insert_query(&self, table_name : &str, props : Vec<&str>, values : Vec<Vec<&str>>) -> String {
+        let esc_table_name = escape_string(table_name);
+        let esc_props : Vec<String> = props.iter().map(|s| -> String {escape_string(s)}).collect();
+        let esc_props_str = esc_props.iter().map(|s| -> &str {s.as_str()}).collect();
+        let mut esc_values :Vec<Vec<String>> = Vec::new();
+        for vec in values {
+           let temp = vec.iter().map(|s| { escape_string(s) }).collect();
+            esc_values.push(temp);
+        }
+        return self.insert_query_unsafe(esc_table_name.as_str(), esc_props_str, esc_values);
+    }
+
     async fn insert_unsafe(&self, table_name : &str, props : Vec<&str>, values : Vec<Vec<String>>) -> Option<usize>;
 
     async fn insert(&self, table_name : &str, props : Vec<&str>, values : Vec<Vec<&str>>) -> Option<usize> {
@@ -32,6 +46,19 @@ pub trait IDbService {
         return self.insert_unsafe(esc_table_name.as_str(), esc_props_str, esc_values).await;
     }
 
+    fn update_query_unsafe(&self, table_name : &str, key_prop : &str, key_value : &str, props : Vec<&str>, values : Vec<&str>) -> String;
+
+    fn update_query(&self, table_name : &str, key_prop : &str, key_value : &str, props : Vec<&str>, values : Vec<&str>) -> String {
+        let esc_table_name = escape_string(table_name);
+        let esc_key_prop = escape_string(key_prop);
+        let esc_key_value = escape_string(key_value);
+        let esc_props : Vec<String> = props.iter().map(|s| -> String {escape_string(s)}).collect();
+        let esc_props_str = esc_props.iter().map(|s| {s.as_str()}).collect();
+        let esc_values : Vec<String> = values.iter().map(|s| -> String {escape_string(s)}).collect();
+        let esc_values_str = esc_values.iter().map(|s| {s.as_str()}).collect();
+        return self.update_query_unsafe(esc_table_name.as_str(), esc_key_prop.as_str(), esc_key_value.as_str(), esc_props_str, esc_values_str);
+    }
+
     async fn update_unsafe(&self, table_name : &str, key_prop : &str, key_value : &str, props : Vec<&str>, values : Vec<&str>);
 
     async fn update(&self, table_name : &str, key_prop : &str, key_value : &str, props : Vec<&str>, values : Vec<&str>) {
@@ -71,6 +98,16 @@ pub trait IDbService {
         return Some(unwrap_v[0].clone());
     }
 
+    fn delete_query_unsafe(&self, table_name : &str, prop : &str, values : Vec<&str>) -> String;
+
+    fn delete_query(&self, table_name : &str, prop : &str, values : Vec<&str>) -> String {
+        let esc_table_name = escape_string(table_name);
+        let esc_prop = escape_string(prop);
+        let esc_values : Vec<String> = values.iter().map(|s| -> String {escape_string(s)}).collect();
+        let esc_values_str = esc_values.iter().map(|s| -> &str {s.as_str()}).collect();
+        return self.delete_query_unsafe(esc_table_name.as_str(), esc_prop.as_str(), esc_values_str);
+    }
+
     async fn delete_many_by_prop_unsafe(&self, table_name : &str, prop : &str, values : Vec<&str>) -> Option<usize>;
 
     async fn delete_many_by_prop(&self, table_name : &str, prop : &str, values : Vec<&str>) -> Option<usize> {
@@ -95,6 +132,10 @@ pub trait IDbService {
         return self.exists_by_prop_unsafe(esc_table_name.as_str(), esc_prop.as_str(), esc_value.as_str()).await;
     }
 
+    // runs all queries on one connection inside a transaction, every query has to change at least one row.
+    // on the first failure everything is rolled back and the error names the failed query
+    async fn execute_in_transaction(&self, queries : Vec<String>) -> Result<usize, String>;
+
     async fn generate_id_unsafe(&self) -> String {
         return String::from(Uuid::new_v4());
     }
@@ -125,7 +166,7 @@ impl IDbService for SQLiteDbService {
         };
      }
 
-    async fn insert_unsafe(&self, table_name : &str, props : Vec<&str>, values : Vec<Vec<String>>) -> Option<usize> {
+    fn insert_query_unsafe(&self, table_name : &str, props : Vec<&str>, values : Vec<Vec<String>>) -> String {
         let props_quoted : Vec<String> = props.iter().map(| s | -> String {
             let result = format!("\"{s}\"");
             return result;
@@ -144,7 +185,11 @@ impl IDbService for SQLiteDbService {
         }
         let values_str = temp_vec.join(", ");
 
-        let query = format!("INSERT INTO \"{table_name}\" ({props_str}) VALUES {values_str}");
+        return format!("INSERT INTO \"{table_name}\" ({props_str}) VALUES {values_str}");
+    }
+
+    async fn insert_unsafe(&self, table_name : &str, props : Vec<&str>, values : Vec<Vec<String>>) -> Option<usize> {
+        let query = self.insert_query_unsafe(table_name, props, values);
         let conn = self.state.db.lock().await;
         let result : Option<usize> = match conn.execute(query.as_str()).await {
             Ok(o) => Some(o.rows_affected().try_into().unwrap()),
@@ -153,9 +198,8 @@ impl IDbService for SQLiteDbService {
         return result;
     }
 
-    async fn update_unsafe(&self, table_name : &str, key_prop : &str, key_value : &str, props : Vec<&str>, values : Vec<&str>) {
+    fn update_query_unsafe(&self, table_name : &str, key_prop : &str, key_value : &str, props : Vec<&str>, values : Vec<&str>) -> String {
         let n = min(props.len(), values.len());
-        if n == 0 { return; }
 
         let mut set_lines = Vec::<String>::new();
         for i in 0..n {
@@ -165,7 +209,12 @@ impl IDbService for SQLiteDbService {
             set_lines.push(temp);
         }
         let set_lines_str = set_lines.join(", ");
-        let query = format!("UPDATE \"{table_name}\" SET {set_lines_str} WHERE \"{key_prop}\" = \'{key_value}\'");
+        return format!("UPDATE \"{table_name}\" SET {set_lines_str} WHERE \"{key_prop}\" = \'{key_value}\'");
+    }
+
+    async fn update_unsafe(&self, table_name : &str, key_prop : &str, key_value : &str, props : Vec<&str>, values : Vec<&str>) {
+        if min(props.len(), values.len()) == 0 { return; }
+        let query = self.update_query_unsafe(table_name, key_prop, key_value, props, values);
         let conn = self.state.db.lock().await;
         let _ = conn.execute(query.as_str()).await;
 
@@ -204,13 +253,17 @@ impl IDbService for SQLiteDbService {
 
     }
 
-    async fn delete_many_by_prop_unsafe(&self, table_name : &str, prop : &str, values : Vec<&str>) -> Option<usize> {
+    fn delete_query_unsafe(&self, table_name : &str, prop : &str, values : Vec<&str>) -> String {
         let values_quoted : Vec<String> = values.iter().map(| s | -> String {
             let result = format!("\'{s}\'");
             return result;
         }).collect();
         let values_str = values_quoted.join(", ");
-        let query = format!("DELETE FROM \"{table_name}\" WHERE \"{prop}\" IN ({values_str})");
+        return format!("DELETE FROM \"{table_name}\" WHERE \"{prop}\" IN ({values_str})");
+    }
+
+    async fn delete_many_by_prop_unsafe(&self, table_name : &str, prop : &str, values : Vec<&str>) -> Option<usize> {
+        let query = self.delete_query_unsafe(table_name, prop, values);
         let conn = self.state.db.lock().await;
         let result : Option<usize> = match conn.execute(query.as_str()).await {
             Ok(o) => Some(o.rows_affected().try_into().unwrap()),
@@ -231,4 +284,31 @@ impl IDbService for SQLiteDbService {
         let val : u8 = row.get("row_exists");
         return Some(val == 1);
     }
+
+    async fn execute_in_transaction(&self, queries : Vec<String>) -> Result<usize, String> {
+        let conn = self.state.db.lock().await;
+        let mut transaction = match conn.begin().await {
+            Ok(o) => o,
+            Err(e) => return Err(format!("Transaction was not started: {e}"))
+        };
+        let mut rows_affected : usize = 0;
+        for (i, query) in queries.iter().enumerate() {
+            let query_rows_affected : usize = match (&mut *transaction).execute(query.as_str()).await {
+                Ok(o) => o.rows_affected().try_into().unwrap(),
+                Err(e) => {
+                    let _ = transaction.rollback().await;
+                    return Err(format!("Query {} of {} failed: {e}", i + 1, queries.len()));
+                }
+            };
+            if query_rows_affected == 0 {
+                let _ = transaction.rollback().await;
+                return Err(format!("Query {} of {} did not change any rows", i + 1, queries.len()));
+            }
+            rows_affected += query_rows_affected;
+        }
+        return match transaction.commit().await {
+            Ok(_) => Ok(rows_affected),
+            Err(e) => Err(format!("Transaction was not committed: {e}"))
+        };
+    }
 }
\ No newline at end of file
@@ -7,7 +7,7 @@ use crate::core::controllers::{auth_router, check_auth, hello, invite_router, pi
 use crate::core::data_model::traits::ILocalObject;
 use crate::core::functions::{generate_id, generate_random_token};
 use crate::core::services::{create_roles_user_info, init_admin_if_not_exists, row_to_account, row_to_role, user_sign_up, IDbService, SQLiteDbService};
-use crate::santa::background_tasks::{delete_old_messages, delete_old_pools};
+use crate::santa::background_tasks::{delete_old_messages, delete_old_pools, end_finished_pools, push_scheduled_pools};
 use crate::santa::controllers::santa_router;
 use crate::santa::functions::santa_init_database;
 use crate::santa::services::row_to_message;
@@ -57,6 +57,8 @@ async fn run_background_tasks(state : &AppState) -> () {
     delete_old_auth_codes(state).await;
     delete_old_messages(state).await;
     delete_old_pools(state).await;
+    end_finished_pools(state).await;
+    push_scheduled_pools(state).await;
 }
 
 // routers groups
@@ -0,0 +1,24 @@
+use std::time::Duration;
+
+use futures::{stream, StreamExt};
+use tokio::time;
+
+
+use crate::{santa::services::end_pools_if_all_gifts_delivered, AppState};
+
+pub async fn end_finished_pools(state : &AppState) -> () {
+    let seconds = state.config.lock().await.santa.finished_pools_check_freq;
+    let interval = time::interval(Duration::from_secs(seconds));
+    let cloned_state = state.clone();
+
+    tokio::spawn(async move {
+        let forever = stream::unfold(interval, |mut interval| async {
+            interval.tick().await;
+            tracing::info!("End finished pools task started...");
+            end_pools_if_all_gifts_delivered(&cloned_state).await;
+            tracing::info!("End finished pools task ended.");
+            Some(((), interval))
+        });
+        forever.for_each(|_| async {}).await;
+    });
+}
\ No newline at end of file
@@ -1,5 +1,9 @@
 mod delete_old_pools;
 mod delete_old_messages;
+mod end_finished_pools;
+mod push_scheduled_pools;
 
 pub use delete_old_pools::*;
-pub use delete_old_messages::*;
\ No newline at end of file
+pub use delete_old_messages::*;
+pub use end_finished_pools::*;
+pub use push_scheduled_pools::*;
\ No newline at end of file
@@ -0,0 +1,24 @@
+use std::time::Duration;
+
+use futures::{stream, StreamExt};
+use tokio::time;
+
+
+use crate::{santa::services::push_pools_if_scheduled, AppState};
+
+pub async fn push_scheduled_pools(state : &AppState) -> () {
+    let seconds = state.config.lock().await.santa.scheduled_pools_check_freq;
+    let interval = time::interval(Duration::from_secs(seconds));
+    let cloned_state = state.clone();
+
+    tokio::spawn(async move {
+        let forever = stream::unfold(interval, |mut interval| async {
+            interval.tick().await;
+            tracing::info!("Push scheduled pools task started...");
+            push_pools_if_scheduled(&cloned_state).await;
+            tracing::info!("Push scheduled pools task ended.");
+            Some(((), interval))
+        });
+        forever.for_each(|_| async {}).await;
+    });
+}
\ No newline at end of file
@@ -1,14 +1,15 @@
-use axum::{routing::{delete, get, post, put}, Router};
+use axum::{body::Body, extract::{Path, Request, State}, http::{HeaderMap, StatusCode}, response::IntoResponse, routing::{delete, get, post, put}, Json, Router};
 use serde::{Deserialize, Serialize};
 use sqlx::sqlite::SqliteRow;
 
-use crate::{core::{controllers::{ApiResponse, ICRUDController, WhoIsExecutor}, data_model::traits::IAccountRelated, services::{IDbService, SQLiteDbService}}, santa::{data_model::{enums::PoolState, implementations::{Member, Pool}, traits::{IPool, IPoolRelated}}, services::{row_to_member, row_to_pool, user_add_member_to_pool}}, AppState};
+use crate::{core::{controllers::{ApiResponse, ICRUDController, WhoIsExecutor}, data_model::traits::{IAccountRelated, ILocalObject}, services::{escape_string, IDbService, SQLiteDbService}}, santa::{data_model::{enums::PoolState, implementations::{Member, Pool}, traits::{IPool, IPoolRelated}}, services::{UserQuestionAnswer, is_pool_organizer, row_to_member, row_to_pool, user_add_member_to_pool, user_delete_member_from_pool}}, AppState};
 
 #[derive(Serialize, Deserialize)]
 pub struct CreateMemberRequestData {
     pub account_id : Option<String>,
     pub pool_id : String,
-    pub wishlist : Option<String>
+    pub wishlist : Option<String>,
+    pub answers : Option<Vec<UserQuestionAnswer>>
 }
 
 pub struct MemberCRUDController {}
@@ -32,7 +33,7 @@ impl MemberCRUDController {
         if pool_opt.is_none() { return (Some(false), WhoIsExecutor::NoMatter); }
         let pool = pool_opt.unwrap();
 
-        let is_pool_owner = pool.account_id() == executor_id;
+        let is_pool_owner = is_pool_organizer(pool.id(), executor_id, state).await;
         if is_pool_owner { return (None, WhoIsExecutor::PoolOwner); }
 
         return (None, WhoIsExecutor::Other);
@@ -51,6 +52,31 @@ impl MemberCRUDController {
 
         return Some(pool);
     }
+
+    // leaving an open pool goes through the pool logic, so the freed place is offered to the waitlist
+    pub async fn user_delete_member_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let member_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap().to_string();
+        if !Self::check_perm_delete(&state, executor_id.as_str(), member_id).await {
+            return Self::access_denied_response().into_response();
+        }
+
+        let db_service = SQLiteDbService::new(&state);
+        let member_opt = db_service.get_one_by_prop(Self::table_name().as_str(), "id", member_id, Self::transform_func()).await;
+        let pool_opt = Self::get_pool_by_member_id(&state, member_id).await;
+        if member_opt.is_none() || pool_opt.is_none() || pool_opt.unwrap().state() != PoolState::Open {
+            return Self::delete_object_by_id_handler(State(state), Path(id), headers, request).await.into_response();
+        }
+        let member = member_opt.unwrap();
+
+        let resp = user_delete_member_from_pool(member.pool_id(), member.account_id(), &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
 }
 
 impl ICRUDController<CreateMemberRequestData, Member> for MemberCRUDController {
@@ -71,7 +97,8 @@ impl ICRUDController<CreateMemberRequestData, Member> for MemberCRUDController {
             return Self::acting_like_another_user_api_response();
         }
         let wishlist = obj.wishlist.unwrap_or(String::new());
-        return user_add_member_to_pool(account_id.as_str(), obj.pool_id.as_str(), wishlist.as_str(), state).await;
+        let answers = obj.answers.unwrap_or(vec![]);
+        return user_add_member_to_pool(account_id.as_str(), obj.pool_id.as_str(), wishlist.as_str(), &answers, false, state).await;
     }
 
     fn objects_router(_ : &AppState) -> Router<AppState> {
@@ -80,7 +107,7 @@ impl ICRUDController<CreateMemberRequestData, Member> for MemberCRUDController {
             .route("/id/{id}", get(Self::get_object_by_id_handler))
             .route("/", post(Self::create_object_handler))
             .route("/id/{id}", put(Self::update_object_by_id_handler))
-            .route("/id/{id}", delete(Self::delete_object_by_id_handler));
+            .route("/id/{id}", delete(Self::user_delete_member_handler));
     }
     
     async fn check_perm_create(_state : &AppState, _executor_id : &str) -> bool {
@@ -2,7 +2,7 @@ use axum::{routing::{delete, get, post, put}, Router};
 use serde::{Deserialize, Serialize};
 use sqlx::sqlite::SqliteRow;
 
-use crate::{core::{controllers::{ApiResponse, ICRUDController, WhoIsExecutor}, data_model::traits::{IAccountRelated, ILocalObject}, services::{IDbService, SQLiteDbService}}, santa::{data_model::{enums::PoolState, implementations::{Message, Pool, Room}, traits::{IPool, IPoolRelated, IRoomRelated}}, services::{row_to_member, row_to_message, row_to_pool, row_to_room, user_send_message_to_room}}, AppState};
+use crate::{core::{controllers::{ApiResponse, ICRUDController, WhoIsExecutor}, data_model::traits::{IAccountRelated, ILocalObject}, services::{IDbService, SQLiteDbService}}, santa::{data_model::{enums::PoolState, implementations::{Message, Pool, Room}, traits::{IPool, IPoolRelated, IRoomRelated}}, services::{is_pool_organizer, row_to_member, row_to_message, row_to_pool, row_to_room, user_send_message_to_room}}, AppState};
 
 #[derive(Serialize, Deserialize)]
 pub struct CreateMessageRequestData {
@@ -61,7 +61,7 @@ impl MessageCRUDController {
         let is_resource_owner = message.account_id() == executor_id;
         if is_resource_owner { return (None, WhoIsExecutor::ResourceOwner); }
 
-        let is_pool_owner = pool.account_id() == executor_id;
+        let is_pool_owner = is_pool_organizer(pool.id(), executor_id, state).await;
         if is_pool_owner { return (None, WhoIsExecutor::PoolOwner); }
 
         return (None, WhoIsExecutor::Other);
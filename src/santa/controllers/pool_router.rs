@@ -1,8 +1,11 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
 use axum::{body::Body, extract::{Path, Request, State}, http::{HeaderMap, StatusCode}, response::IntoResponse, routing::{delete, get, post, put}, Json, Router};
 use serde::{Deserialize, Serialize};
 use sqlx::sqlite::SqliteRow;
 
-use crate::{core::{controllers::{ApiResponse, ApiResponseStatus, ICRUDController, WhoIsExecutor}, data_model::traits::IAccountRelated, services::{escape_string, IDbService, SQLiteDbService}}, santa::{data_model::{enums::PoolState, implementations::Pool, traits::IPool}, services::{get_pool_by_id, row_to_pool, user_create_pool, user_delete_member_from_pool, user_delete_pool, user_get_member_nicknames_in_pool, user_pool_state_push}}, AppState};
+use crate::{core::{controllers::{ApiResponse, ApiResponseStatus, ICRUDController, WhoIsExecutor}, data_model::traits::{IAccountRelated, ILocalObject}, services::{escape_string, IDbService, SQLiteDbService}}, santa::{data_model::{enums::{PoolState, PoolVisibility, RoomState}, implementations::Pool, traits::{IPool, IPoolRelated}}, functions::{normalize_currency_code, DeliveryAddress}, services::{user_add_pool_question, user_delete_pool_question, user_get_my_answers_in_pool, user_get_pool_questions, user_set_my_answers_in_pool, UserQuestionAnswer, user_create_pool_announcement, user_delete_pool_announcement, user_get_pool_announcements, user_update_pool_announcement, user_get_pool_stats, user_archive_pool, pool_price_range_error, user_get_pool_gift_spends, get_pool_organizer_by_pool_and_account_ids, get_pool_organizers_by_account_id, user_add_pool_organizer, user_delete_pool_organizer, user_get_pool_organizers, user_transfer_pool_ownership, get_pool_invitations_by_account_id, user_accept_pool_invitation, user_clone_pool, user_decline_pool_invitation, user_get_my_pool_invitations, user_get_pool_invitations, user_delete_from_waitlist, user_get_my_waitlist_position, user_get_pool_waitlist, user_promote_from_waitlist, user_set_pool_max_members, get_members_by_account_id, get_pool_by_id, is_member_already_exists_by_pool_and_account_ids, is_pool_already_exists_by_id, is_pool_visible_to_account, user_create_pool_join_token, user_get_pool_by_join_token, user_get_pool_join_tokens, user_join_pool_by_token, user_revoke_pool_join_token, user_set_pool_visibility, row_to_pool, user_add_exclusion_to_pool, user_create_pool, user_delete_exclusion_from_pool, user_delete_member_from_pool, user_drop_out_member_from_pool, user_delete_my_address_in_pool, user_delete_pool, user_get_cross_group_preview, user_get_draw_preview, user_get_draw_proof, user_get_exclusions_in_pool, user_get_member_nicknames_in_pool, user_get_my_address_in_pool, user_get_overdue_rooms, user_get_pool_receipts, user_get_pool_room_stages, user_get_pool_stage_deadlines, user_pool_state_push, user_set_member_group_in_pool, user_set_my_address_in_pool, user_set_pool_schedule, user_set_pool_stage_deadlines, UserStageDeadline}}, AppState};
 
 #[derive(Serialize, Deserialize)]
 pub struct CreatePoolRequestData {
@@ -10,7 +13,104 @@ pub struct CreatePoolRequestData {
     pub description : String,
     pub account_id : Option<String>,
     pub min_price : u64,
-    pub max_price : u64
+    pub max_price : u64,
+    pub previous_pool_id : Option<String>,
+    pub history_depth : Option<u64>,
+    pub gifts_per_member : Option<u64>,
+    pub cross_group_draw : Option<bool>,
+    pub end_room_state : Option<RoomState>,
+    pub open_at : Option<DateTime<Utc>>,
+    pub draw_at : Option<DateTime<Utc>>,
+    pub end_at : Option<DateTime<Utc>>,
+    pub visibility : Option<PoolVisibility>,
+    pub max_members : Option<u64>,
+    pub currency : Option<String>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateExclusionRequestData {
+    pub first_account_id : Option<String>,
+    pub second_account_id : String
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SetMemberGroupRequestData {
+    pub group_name : String
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SetPoolScheduleRequestData {
+    pub open_at : Option<DateTime<Utc>>,
+    pub draw_at : Option<DateTime<Utc>>,
+    pub end_at : Option<DateTime<Utc>>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SetPoolVisibilityRequestData {
+    pub visibility : PoolVisibility
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AddPoolOrganizerRequestData {
+    pub account_id : String
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TransferPoolOwnershipRequestData {
+    pub account_id : String,
+    pub keep_as_organizer : Option<bool>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ClonePoolRequestData {
+    pub name : String,
+    pub description : Option<String>,
+    pub invite_members : Option<bool>,
+    pub link_history : Option<bool>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreatePoolAnnouncementRequestData {
+    pub text_content : String,
+    pub is_pinned : Option<bool>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdatePoolAnnouncementRequestData {
+    pub text_content : Option<String>,
+    pub is_pinned : Option<bool>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SetPoolMaxMembersRequestData {
+    pub max_members : u64
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreatePoolJoinTokenRequestData {
+    pub max_uses : Option<u64>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct JoinPoolRequestData {
+    pub wishlist : Option<String>,
+    pub answers : Option<Vec<UserQuestionAnswer>>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AddPoolQuestionRequestData {
+    pub text_content : String,
+    pub is_required : Option<bool>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SetMyAnswersRequestData {
+    pub answers : Vec<UserQuestionAnswer>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SetStageDeadlinesRequestData {
+    pub deadlines : Vec<UserStageDeadline>
 }
 
 pub struct PoolCRUDController {}
@@ -29,10 +129,30 @@ impl PoolCRUDController {
         let is_resource_owner = pool.account_id() == executor_id;
         if is_resource_owner { return (None, WhoIsExecutor::ResourceOwner); }
 
+        let is_pool_organizer = get_pool_organizer_by_pool_and_account_ids(object_id, executor_id, state).await.is_some();
+        if is_pool_organizer { return (None, WhoIsExecutor::PoolOwner); }
+
         return (None, WhoIsExecutor::Other);
     }
 
-    pub async fn user_get_member_nicknames_in_pool_handler(State(state) : State<AppState>, Path(id) : Path<String>, _headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+    // admins, moderators, the owner and co-organizers manage the pool
+    fn is_pool_organizer_role(role : &WhoIsExecutor) -> bool {
+        return *role == WhoIsExecutor::Admin || *role == WhoIsExecutor::Moderator || *role == WhoIsExecutor::ResourceOwner || *role == WhoIsExecutor::PoolOwner;
+    }
+
+    // co-organizers are left out on purpose: only the owner manages organizers, hands the pool over, archives and deletes it
+    fn is_pool_owner_role(role : &WhoIsExecutor) -> bool {
+        return *role == WhoIsExecutor::Admin || *role == WhoIsExecutor::Moderator || *role == WhoIsExecutor::ResourceOwner;
+    }
+
+    pub async fn user_get_member_nicknames_in_pool_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        if !Self::check_perm_get(&state, executor_id, pool_id).await {
+            return Self::access_denied_response().into_response();
+        }
+
         let result = user_get_member_nicknames_in_pool(id.as_str(), &state).await;
         if result.is_ok() {
             return (StatusCode::OK, Json(result)).into_response()
@@ -48,7 +168,7 @@ impl PoolCRUDController {
            return Self::access_denied_response().into_response();
         }
 
-        if role == WhoIsExecutor::Admin || role == WhoIsExecutor::Moderator || role == WhoIsExecutor::NoMatter || role == WhoIsExecutor::ResourceOwner {
+        if role == WhoIsExecutor::NoMatter || Self::is_pool_organizer_role(&role) {
             let resp = user_pool_state_push(id.as_str(), &state).await;
             if resp.is_ok() {
                 return (StatusCode::OK, Json(resp)).into_response();
@@ -78,13 +198,33 @@ impl PoolCRUDController {
         if basic_check.is_some_and(|b| {!b}) {
             return Self::access_denied_response().into_response();
         }
-        if role == WhoIsExecutor::Admin || role == WhoIsExecutor::Moderator || role == WhoIsExecutor::NoMatter || role == WhoIsExecutor::ResourceOwner {
+        if role == WhoIsExecutor::NoMatter || Self::is_pool_organizer_role(&role) {
             let resp = user_delete_member_from_pool(pool_id, acc_id, &state).await;
             return (StatusCode::OK, Json(resp)).into_response();
         }
         return Self::access_denied_response().into_response();
     }
 
+    pub async fn user_drop_out_member_from_pool_handler(State(state) : State<AppState>, Path((id, account_id)) : Path<(String, String)>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id= esc_id_string.as_str();
+        let esc_acc_id_string = escape_string(account_id.as_str());
+        let acc_id = esc_acc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = PoolCRUDController::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if Self::is_pool_organizer_role(&role) {
+            let resp = user_drop_out_member_from_pool(pool_id, acc_id, executor_id, &state).await;
+            if !resp.is_ok() {
+                return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+            }
+            return (StatusCode::OK, Json(resp)).into_response();
+        }
+        return Self::access_denied_response().into_response();
+    }
+
     pub async fn user_is_pool_owner_or_admin_or_moderator_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
         let esc_id_string = escape_string(id.as_str());
         let pool_id= esc_id_string.as_str();
@@ -97,96 +237,1063 @@ impl PoolCRUDController {
             return (StatusCode::NOT_FOUND, Json(resp)).into_response();
         }
 
-        let result = role == WhoIsExecutor::ResourceOwner || role == WhoIsExecutor::Admin || role == WhoIsExecutor::Moderator;
+        let result = Self::is_pool_organizer_role(&role);
         let resp = ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
         return (StatusCode::OK, Json(resp)).into_response();
     }
 
-    pub async fn user_delete_pool_by_id_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+    pub async fn user_get_exclusions_in_pool_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
         let esc_id_string = escape_string(id.as_str());
-        let pool_id= esc_id_string.as_str();
+        let pool_id = esc_id_string.as_str();
         let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
-        let (_, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
-        
-        if role == WhoIsExecutor::NoMatter {
-            let err_msg = format!("Pool with id \"{pool_id}\" not found");
-            let resp = ApiResponse::new(ApiResponseStatus::WARNING, serde_json::to_value(err_msg.as_str()).unwrap());
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let is_organizer = Self::is_pool_organizer_role(&role);
+        let resp = user_get_exclusions_in_pool(pool_id, executor_id, is_organizer, &state).await;
+        if resp.is_ok() {
             return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
         }
+    }
 
-        let access = role == WhoIsExecutor::ResourceOwner || role == WhoIsExecutor::Admin || role == WhoIsExecutor::Moderator;
-        if !access { return Self::access_denied_response().into_response(); }
+    pub async fn user_add_exclusion_to_pool_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<CreateExclusionRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
 
-        let pool = get_pool_by_id(pool_id, &state).await.unwrap();
-        if pool.state() != PoolState::Ended {
-            let err_msg = format!("Pool with id \"{pool_id}\" is not ended. Only ended pools can be deleted");
-            let resp = ApiResponse::error_from_str(err_msg.as_str());
+        let first_account_id_string = escape_string(json.first_account_id.unwrap_or(String::from(executor_id)).as_str());
+        let second_account_id_string = escape_string(json.second_account_id.as_str());
+        let is_organizer = Self::is_pool_organizer_role(&role);
+        let resp = user_add_exclusion_to_pool(pool_id, executor_id, first_account_id_string.as_str(), second_account_id_string.as_str(), is_organizer, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
             return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
         }
-        user_delete_pool(pool_id, &state).await;
+    }
 
-        let msg = format!("Pool with id \"{pool_id}\" was successfully deleted");
-        let resp = ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg.as_str()).unwrap());
-        return (StatusCode::OK, Json(resp)).into_response();
+    pub async fn user_set_member_group_in_pool_handler(State(state) : State<AppState>, Path((id, account_id)) : Path<(String, String)>, headers : HeaderMap, Json(json) : Json<SetMemberGroupRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let esc_acc_id_string = escape_string(account_id.as_str());
+        let acc_id = esc_acc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_set_member_group_in_pool(pool_id, acc_id, json.group_name.as_str(), &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
     }
-}
 
-impl ICRUDController<CreatePoolRequestData, Pool> for PoolCRUDController {
-    fn object_type_name() -> String { return String::from("pool"); }
+    pub async fn user_get_cross_group_preview_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
 
-    fn table_name() -> String { return String::from("pools"); }
+        let resp = user_get_cross_group_preview(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
 
-    fn transform_func() -> fn(&SqliteRow) -> Pool { return row_to_pool; }
+    pub async fn user_get_draw_preview_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
 
-    async fn create_object_and_return_id(executor_id : &str, obj : CreatePoolRequestData, state : &AppState) -> ApiResponse {
-        let account_id = obj.account_id.unwrap_or(String::from(executor_id));
-        return user_create_pool(obj.name.as_str(), obj.description.as_str(), account_id.as_str(), obj.min_price, obj.max_price, state).await;
+        let resp = user_get_draw_preview(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
     }
 
-    fn objects_router(_ : &AppState) -> Router<AppState> {
-        return Router::new()
-            .route("/", get(Self::get_objects_list_handler))
-            .route("/id/{id}", get(Self::get_object_by_id_handler))
-            .route("/", post(Self::create_object_handler))
-            .route("/id/{id}", put(Self::update_object_by_id_handler))
-            .route("/id/{id}", delete(Self::delete_object_by_id_handler))
+    pub async fn user_get_pool_room_stages_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_pool_room_stages(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
     }
-    
-    async fn check_perm_create(_state : &AppState, _executor_id : &str) -> bool {
-        return true;
+
+    pub async fn user_get_pool_receipts_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_pool_receipts(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
     }
-    
-    async fn filter_many(state : &AppState, _executor_id : &str) -> Option<Vec<Pool>> {
-        let db_service = SQLiteDbService::new(state);
-        return db_service.get_all(Self::table_name().as_str(), Self::transform_func()).await;
+
+    pub async fn user_set_my_address_in_pool_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<DeliveryAddress>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+
+        let resp = user_set_my_address_in_pool(pool_id, executor_id, json, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
     }
-    
-    async fn check_perm_update(state : &AppState, executor_id : &str, object_id : &str) -> bool {
-        let (basic_check, role) = Self::basic_check_owner(state, executor_id, object_id).await;
-        if basic_check.is_some() { return basic_check.unwrap(); }
-        if role == WhoIsExecutor::Other { return false; }
 
-        if role == WhoIsExecutor::ResourceOwner {
-            return true;
+    pub async fn user_get_my_address_in_pool_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+
+        let resp = user_get_my_address_in_pool(pool_id, executor_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
         }
+    }
 
-        return false;
+    pub async fn user_delete_my_address_in_pool_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+
+        let resp = user_delete_my_address_in_pool(pool_id, executor_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
     }
-    
-    async fn check_perm_delete(state : &AppState, executor_id : &str, object_id : &str) -> bool {
-        let (basic_check, role) = Self::basic_check_owner(state, executor_id, object_id).await;
-        if basic_check.is_some() { return basic_check.unwrap(); }
-        if role == WhoIsExecutor::Other { return false; }
-        
-        let db_service = SQLiteDbService::new(state);
-        let pool_opt = db_service.get_one_by_prop(Self::table_name().as_str(), "id", object_id, Self::transform_func()).await;
-        if pool_opt.is_none() { return true; }
-        let pool = pool_opt.unwrap();
 
-        if role == WhoIsExecutor::ResourceOwner && pool.state() == PoolState::Ended {
-            return true;
+    pub async fn user_delete_exclusion_from_pool_handler(State(state) : State<AppState>, Path((id, exclusion_id)) : Path<(String, String)>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let esc_exclusion_id_string = escape_string(exclusion_id.as_str());
+        let exclusion_id = esc_exclusion_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let is_organizer = Self::is_pool_organizer_role(&role);
+        let resp = user_delete_exclusion_from_pool(pool_id, exclusion_id, executor_id, is_organizer, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_draw_proof_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let is_member = is_member_already_exists_by_pool_and_account_ids(pool_id, executor_id, &state).await;
+        if role == WhoIsExecutor::Other && !is_member {
+            return Self::access_denied_response().into_response();
         }
 
+        let resp = user_get_draw_proof(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_pool_stage_deadlines_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let is_member = is_member_already_exists_by_pool_and_account_ids(pool_id, executor_id, &state).await;
+        if role == WhoIsExecutor::Other && !is_member {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_pool_stage_deadlines(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_set_pool_stage_deadlines_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<SetStageDeadlinesRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_set_pool_stage_deadlines(pool_id, json.deadlines, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_set_pool_schedule_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<SetPoolScheduleRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_set_pool_schedule(pool_id, json.open_at, json.draw_at, json.end_at, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_set_pool_visibility_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<SetPoolVisibilityRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_set_pool_visibility(pool_id, json.visibility, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_pool_organizers_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        if !Self::check_perm_get(&state, executor_id, pool_id).await {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_pool_organizers(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_add_pool_organizer_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<AddPoolOrganizerRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let esc_acc_id_string = escape_string(json.account_id.as_str());
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_owner_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_add_pool_organizer(pool_id, esc_acc_id_string.as_str(), &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_delete_pool_organizer_handler(State(state) : State<AppState>, Path((id, account_id)) : Path<(String, String)>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let esc_acc_id_string = escape_string(account_id.as_str());
+        let acc_id = esc_acc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        // co-organizers can step down by themselves
+        if !Self::is_pool_owner_role(&role) && acc_id != executor_id {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_delete_pool_organizer(pool_id, acc_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_transfer_pool_ownership_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<TransferPoolOwnershipRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let esc_acc_id_string = escape_string(json.account_id.as_str());
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_owner_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let keep_as_organizer = json.keep_as_organizer.unwrap_or(true);
+        let resp = user_transfer_pool_ownership(pool_id, esc_acc_id_string.as_str(), keep_as_organizer, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_clone_pool_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<ClonePoolRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let invite_members = json.invite_members.unwrap_or(false);
+        let link_history = json.link_history.unwrap_or(false);
+        let resp = user_clone_pool(pool_id, json.name.as_str(), json.description, invite_members, link_history, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_pool_invitations_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_pool_invitations(pool_id, &state).await;
+        return (StatusCode::OK, Json(resp)).into_response();
+    }
+
+    pub async fn user_get_my_pool_invitations_handler(State(state) : State<AppState>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let resp = user_get_my_pool_invitations(executor_id, &state).await;
+        return (StatusCode::OK, Json(resp)).into_response();
+    }
+
+    pub async fn user_accept_pool_invitation_handler(State(state) : State<AppState>, Path(invitation_id) : Path<String>, headers : HeaderMap, Json(json) : Json<JoinPoolRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(invitation_id.as_str());
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, _) = Self::basic_check_perm(&state, executor_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let wishlist = json.wishlist.unwrap_or(String::new());
+        let answers = json.answers.unwrap_or(vec![]);
+        let resp = user_accept_pool_invitation(esc_id_string.as_str(), executor_id, wishlist.as_str(), &answers, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_decline_pool_invitation_handler(State(state) : State<AppState>, Path(invitation_id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(invitation_id.as_str());
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let resp = user_decline_pool_invitation(esc_id_string.as_str(), executor_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_set_pool_max_members_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<SetPoolMaxMembersRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_set_pool_max_members(pool_id, json.max_members, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_pool_waitlist_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_pool_waitlist(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_my_waitlist_position_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let resp = user_get_my_waitlist_position(pool_id, executor_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_delete_from_waitlist_handler(State(state) : State<AppState>, Path((id, account_id)) : Path<(String, String)>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let esc_acc_id_string = escape_string(account_id.as_str());
+        let acc_id = esc_acc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        // users can always leave the waitlist by themselves
+        if !Self::is_pool_organizer_role(&role) && acc_id != executor_id {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_delete_from_waitlist(pool_id, acc_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_promote_from_waitlist_handler(State(state) : State<AppState>, Path((id, account_id)) : Path<(String, String)>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let esc_acc_id_string = escape_string(account_id.as_str());
+        let acc_id = esc_acc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_promote_from_waitlist(pool_id, acc_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_pool_join_tokens_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_pool_join_tokens(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_create_pool_join_token_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<CreatePoolJoinTokenRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let max_uses = json.max_uses.unwrap_or(0);
+        let resp = user_create_pool_join_token(pool_id, max_uses, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_revoke_pool_join_token_handler(State(state) : State<AppState>, Path((id, join_token_id)) : Path<(String, String)>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let esc_join_token_id_string = escape_string(join_token_id.as_str());
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_revoke_pool_join_token(pool_id, esc_join_token_id_string.as_str(), &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_pool_by_join_token_handler(State(state) : State<AppState>, Path(token) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_token_string = escape_string(token.as_str());
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, _) = Self::basic_check_perm(&state, executor_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_pool_by_join_token(esc_token_string.as_str(), &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_join_pool_by_token_handler(State(state) : State<AppState>, Path(token) : Path<String>, headers : HeaderMap, Json(json) : Json<JoinPoolRequestData>) -> impl IntoResponse {
+        let esc_token_string = escape_string(token.as_str());
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, _) = Self::basic_check_perm(&state, executor_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let wishlist = json.wishlist.unwrap_or(String::new());
+        let answers = json.answers.unwrap_or(vec![]);
+        let resp = user_join_pool_by_token(esc_token_string.as_str(), executor_id, wishlist.as_str(), &answers, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_archive_pool_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_owner_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_archive_pool(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_pool_gift_spends_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_pool_gift_spends(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_pool_stats_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_pool_stats(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_pool_announcements_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let is_member = is_member_already_exists_by_pool_and_account_ids(pool_id, executor_id, &state).await;
+        if role == WhoIsExecutor::Other && !is_member {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_pool_announcements(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_create_pool_announcement_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<CreatePoolAnnouncementRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_create_pool_announcement(pool_id, executor_id, json.text_content.as_str(), json.is_pinned.unwrap_or(false), &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_update_pool_announcement_handler(State(state) : State<AppState>, Path((id, announcement_id)) : Path<(String, String)>, headers : HeaderMap, Json(json) : Json<UpdatePoolAnnouncementRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let esc_announcement_id_string = escape_string(announcement_id.as_str());
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_update_pool_announcement(pool_id, esc_announcement_id_string.as_str(), json.text_content, json.is_pinned, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_delete_pool_announcement_handler(State(state) : State<AppState>, Path((id, announcement_id)) : Path<(String, String)>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let esc_announcement_id_string = escape_string(announcement_id.as_str());
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_delete_pool_announcement(pool_id, esc_announcement_id_string.as_str(), &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_pool_questions_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        if !Self::check_perm_get(&state, executor_id, pool_id).await {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_pool_questions(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_add_pool_question_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<AddPoolQuestionRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_add_pool_question(pool_id, json.text_content.as_str(), json.is_required.unwrap_or(false), &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_delete_pool_question_handler(State(state) : State<AppState>, Path((id, question_id)) : Path<(String, String)>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let esc_question_id_string = escape_string(question_id.as_str());
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_delete_pool_question(pool_id, esc_question_id_string.as_str(), &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_my_answers_in_pool_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let resp = user_get_my_answers_in_pool(pool_id, executor_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_set_my_answers_in_pool_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<SetMyAnswersRequestData>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let resp = user_set_my_answers_in_pool(pool_id, executor_id, &json.answers, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    pub async fn user_get_overdue_rooms_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (basic_check, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        if basic_check.is_some_and(|b| {!b}) {
+            return Self::access_denied_response().into_response();
+        }
+        if !Self::is_pool_organizer_role(&role) {
+            return Self::access_denied_response().into_response();
+        }
+
+        let resp = user_get_overdue_rooms(pool_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    // the price range stays consistent whichever of its fields is changed
+    pub async fn user_update_pool_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(mut json) : Json<HashMap<String, serde_json::Value>>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id = esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        if !Self::check_perm_update(&state, executor_id, pool_id).await {
+            return Self::access_denied_response().into_response();
+        }
+
+        // the other fields have dedicated endpoints with their own checks, the owner changes only through transfer_ownership
+        let updatable_fields = vec!["name", "description", "min_price", "max_price", "currency"];
+        let forbidden_field_opt = json.keys().find(|k| {!updatable_fields.contains(&k.as_str())});
+        if forbidden_field_opt.is_some() {
+            let field_name = forbidden_field_opt.unwrap();
+            let err_msg = format!("Field \"{field_name}\" can't be updated this way");
+            let resp = ApiResponse::error_from_str(err_msg.as_str());
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+
+        let pool_opt = get_pool_by_id(pool_id, &state).await;
+        if pool_opt.is_none() || !["min_price", "max_price", "currency"].iter().any(|k| {json.contains_key(*k)}) {
+            return Self::update_object_by_id_handler(State(state), Path(id), headers, Json(json)).await.into_response();
+        }
+        let pool = pool_opt.unwrap();
+
+        let min_price_opt = json.get("min_price").map_or(Some(pool.min_price()), |v| {v.as_str().and_then(|s| {s.parse::<u64>().ok()})});
+        let max_price_opt = json.get("max_price").map_or(Some(pool.max_price()), |v| {v.as_str().and_then(|s| {s.parse::<u64>().ok()})});
+        if min_price_opt.is_none() || max_price_opt.is_none() {
+            let resp = ApiResponse::error_from_str("Prices must be non-negative integers passed as strings");
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+        let currency = normalize_currency_code(json.get("currency").and_then(|v| {v.as_str()}).unwrap_or(pool.currency()));
+        let price_err_opt = pool_price_range_error(min_price_opt.unwrap(), max_price_opt.unwrap(), currency.as_str());
+        if price_err_opt.is_some() {
+            let resp = ApiResponse::error_from_str(price_err_opt.unwrap().as_str());
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+
+        if json.contains_key("currency") {
+            json.insert(String::from("currency"), serde_json::Value::from(currency));
+        }
+        return Self::update_object_by_id_handler(State(state), Path(id), headers, Json(json)).await.into_response();
+    }
+
+    pub async fn user_delete_pool_by_id_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let esc_id_string = escape_string(id.as_str());
+        let pool_id= esc_id_string.as_str();
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let (_, role) = Self::basic_check_owner(&state, executor_id, pool_id).await;
+        
+        if role == WhoIsExecutor::NoMatter {
+            let err_msg = format!("Pool with id \"{pool_id}\" not found");
+            let resp = ApiResponse::new(ApiResponseStatus::WARNING, serde_json::to_value(err_msg.as_str()).unwrap());
+            return (StatusCode::OK, Json(resp)).into_response();
+        }
+
+        let access = Self::is_pool_owner_role(&role);
+        if !access { return Self::access_denied_response().into_response(); }
+
+        // an ended pool is archived first, only an archived one is deleted for good
+        let pool = get_pool_by_id(pool_id, &state).await.unwrap();
+        if pool.state() == PoolState::Ended {
+            let resp = user_archive_pool(pool_id, &state).await;
+            return (StatusCode::OK, Json(resp)).into_response();
+        }
+        if pool.state() != PoolState::Archived {
+            let err_msg = format!("Pool with id \"{pool_id}\" is not ended. Only ended pools can be deleted");
+            let resp = ApiResponse::error_from_str(err_msg.as_str());
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+        user_delete_pool(pool_id, &state).await;
+
+        let msg = format!("Pool with id \"{pool_id}\" was successfully deleted");
+        let resp = ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg.as_str()).unwrap());
+        return (StatusCode::OK, Json(resp)).into_response();
+    }
+}
+
+impl ICRUDController<CreatePoolRequestData, Pool> for PoolCRUDController {
+    fn object_type_name() -> String { return String::from("pool"); }
+
+    fn table_name() -> String { return String::from("pools"); }
+
+    fn transform_func() -> fn(&SqliteRow) -> Pool { return row_to_pool; }
+
+    async fn create_object_and_return_id(executor_id : &str, obj : CreatePoolRequestData, state : &AppState) -> ApiResponse {
+        let account_id = obj.account_id.unwrap_or(String::from(executor_id));
+        let previous_pool_id = escape_string(obj.previous_pool_id.unwrap_or(String::new()).as_str());
+        let history_depth = obj.history_depth.unwrap_or(0);
+        let gifts_per_member = obj.gifts_per_member.unwrap_or(1);
+        let cross_group_draw = obj.cross_group_draw.unwrap_or(false);
+        let end_room_state = obj.end_room_state.unwrap_or(RoomState::RecipientTookTheGift);
+        let visibility = obj.visibility.unwrap_or(PoolVisibility::Public);
+        let max_members = obj.max_members.unwrap_or(0);
+        let default_currency = state.config.lock().await.santa.default_currency.clone();
+        let currency = normalize_currency_code(obj.currency.unwrap_or(default_currency).as_str());
+        return user_create_pool(obj.name.as_str(), obj.description.as_str(), account_id.as_str(), obj.min_price, obj.max_price, previous_pool_id.as_str(), history_depth, gifts_per_member, cross_group_draw, end_room_state, obj.open_at, obj.draw_at, obj.end_at, visibility, max_members, currency.as_str(), state).await;
+    }
+
+    fn objects_router(_ : &AppState) -> Router<AppState> {
+        return Router::new()
+            .route("/", get(Self::get_objects_list_handler))
+            .route("/id/{id}", get(Self::get_object_by_id_handler))
+            .route("/", post(Self::create_object_handler))
+            .route("/id/{id}", put(Self::user_update_pool_handler))
+            .route("/id/{id}", delete(Self::delete_object_by_id_handler))
+    }
+    
+    async fn check_perm_create(_state : &AppState, _executor_id : &str) -> bool {
+        return true;
+    }
+    
+    async fn filter_many(state : &AppState, executor_id : &str) -> Option<Vec<Pool>> {
+        let db_service = SQLiteDbService::new(state);
+        let pools_opt = db_service.get_all(Self::table_name().as_str(), Self::transform_func()).await;
+
+        let (basic_check, _) = Self::basic_check_perm(state, executor_id).await;
+        if basic_check.is_some_and(|b| {b}) { return pools_opt; }
+        if basic_check.is_some_and(|b| {!b}) { return None; }
+
+        // only public pools are listed, unlisted and private ones show up for their organizers, members and invited users
+        let members = get_members_by_account_id(executor_id, state).await.unwrap_or(vec![]);
+        let invitations = get_pool_invitations_by_account_id(executor_id, state).await.unwrap_or(vec![]);
+        let organizers = get_pool_organizers_by_account_id(executor_id, state).await.unwrap_or(vec![]);
+        let mut member_pool_ids : Vec<&str> = members.iter().map(|m| {m.pool_id()}).collect();
+        member_pool_ids.extend(invitations.iter().map(|i| {i.pool_id()}));
+        member_pool_ids.extend(organizers.iter().map(|o| {o.pool_id()}));
+        return pools_opt.map(|pools| {pools.into_iter().filter(|p| {
+            PoolVisibility::Public == p.visibility() || p.account_id() == executor_id || member_pool_ids.contains(&p.id())
+        }).collect()});
+    }
+
+    async fn check_perm_get(state : &AppState, executor_id : &str, object_id : &str) -> bool {
+        let (basic_check, _) = Self::basic_check_perm(state, executor_id).await;
+        if basic_check.is_some() { return basic_check.unwrap(); }
+
+        let pool_exists = is_pool_already_exists_by_id(object_id, state).await;
+        if pool_exists.is_none_or(|b| {!b}) { return true; }
+        return is_pool_visible_to_account(object_id, executor_id, state).await;
+    }
+    
+    async fn check_perm_update(state : &AppState, executor_id : &str, object_id : &str) -> bool {
+        let (basic_check, role) = Self::basic_check_owner(state, executor_id, object_id).await;
+        if basic_check.is_some() { return basic_check.unwrap(); }
+        if role == WhoIsExecutor::Other { return false; }
+
+        if role == WhoIsExecutor::ResourceOwner || role == WhoIsExecutor::PoolOwner {
+            return true;
+        }
+
+        return false;
+    }
+    
+    async fn check_perm_delete(_state : &AppState, _executor_id : &str, _object_id : &str) -> bool {
+        // the generic delete removes only the pool row: pools go through remove_pool,
+        // which archives an ended pool first and deletes everything an archived one owns
         return false;
     }
 }
@@ -197,8 +1304,57 @@ pub fn pool_router(state : &AppState) -> Router<AppState> {
     .route("/id/{id}/push_state", post(PoolCRUDController::user_push_pool_state_handler))
     .route("/id/{id}/remove_me", delete(PoolCRUDController::user_delete_me_from_pool_handler))
     .route("/id/{id}/remove_member/{account_id}", delete(PoolCRUDController::user_delete_member_from_pool_handler))
+    .route("/id/{id}/drop_out_member/{account_id}", post(PoolCRUDController::user_drop_out_member_from_pool_handler))
     .route("/id/{id}/am_i_resource_owner", get(PoolCRUDController::user_is_pool_owner_or_admin_or_moderator_handler))
-    .route("/id/{id}/remove_pool", delete(PoolCRUDController::user_delete_pool_by_id_handler));
+    .route("/id/{id}/remove_pool", delete(PoolCRUDController::user_delete_pool_by_id_handler))
+    .route("/id/{id}/archive", post(PoolCRUDController::user_archive_pool_handler))
+    .route("/id/{id}/exclusions", get(PoolCRUDController::user_get_exclusions_in_pool_handler))
+    .route("/id/{id}/exclusions", post(PoolCRUDController::user_add_exclusion_to_pool_handler))
+    .route("/id/{id}/exclusions/{exclusion_id}", delete(PoolCRUDController::user_delete_exclusion_from_pool_handler))
+    .route("/id/{id}/draw_proof", get(PoolCRUDController::user_get_draw_proof_handler))
+    .route("/id/{id}/set_member_group/{account_id}", put(PoolCRUDController::user_set_member_group_in_pool_handler))
+    .route("/id/{id}/cross_group_preview", get(PoolCRUDController::user_get_cross_group_preview_handler))
+    .route("/id/{id}/draw_preview", get(PoolCRUDController::user_get_draw_preview_handler))
+    .route("/id/{id}/room_stages", get(PoolCRUDController::user_get_pool_room_stages_handler))
+    .route("/id/{id}/receipts", get(PoolCRUDController::user_get_pool_receipts_handler))
+    .route("/id/{id}/my_address", get(PoolCRUDController::user_get_my_address_in_pool_handler))
+    .route("/id/{id}/my_address", put(PoolCRUDController::user_set_my_address_in_pool_handler))
+    .route("/id/{id}/my_address", delete(PoolCRUDController::user_delete_my_address_in_pool_handler))
+    .route("/id/{id}/my_answers", get(PoolCRUDController::user_get_my_answers_in_pool_handler))
+    .route("/id/{id}/my_answers", put(PoolCRUDController::user_set_my_answers_in_pool_handler))
+    .route("/id/{id}/stage_deadlines", get(PoolCRUDController::user_get_pool_stage_deadlines_handler))
+    .route("/id/{id}/stage_deadlines", put(PoolCRUDController::user_set_pool_stage_deadlines_handler))
+    .route("/id/{id}/gift_spends", get(PoolCRUDController::user_get_pool_gift_spends_handler))
+    .route("/id/{id}/stats", get(PoolCRUDController::user_get_pool_stats_handler))
+    .route("/id/{id}/announcements", get(PoolCRUDController::user_get_pool_announcements_handler))
+    .route("/id/{id}/announcements", post(PoolCRUDController::user_create_pool_announcement_handler))
+    .route("/id/{id}/announcements/{announcement_id}", put(PoolCRUDController::user_update_pool_announcement_handler))
+    .route("/id/{id}/announcements/{announcement_id}", delete(PoolCRUDController::user_delete_pool_announcement_handler))
+    .route("/id/{id}/questions", get(PoolCRUDController::user_get_pool_questions_handler))
+    .route("/id/{id}/questions", post(PoolCRUDController::user_add_pool_question_handler))
+    .route("/id/{id}/questions/{question_id}", delete(PoolCRUDController::user_delete_pool_question_handler))
+    .route("/id/{id}/overdue_rooms", get(PoolCRUDController::user_get_overdue_rooms_handler))
+    .route("/id/{id}/schedule", put(PoolCRUDController::user_set_pool_schedule_handler))
+    .route("/id/{id}/visibility", put(PoolCRUDController::user_set_pool_visibility_handler))
+    .route("/id/{id}/join_tokens", get(PoolCRUDController::user_get_pool_join_tokens_handler))
+    .route("/id/{id}/join_tokens", post(PoolCRUDController::user_create_pool_join_token_handler))
+    .route("/id/{id}/join_tokens/{join_token_id}", delete(PoolCRUDController::user_revoke_pool_join_token_handler))
+    .route("/id/{id}/max_members", put(PoolCRUDController::user_set_pool_max_members_handler))
+    .route("/id/{id}/waitlist", get(PoolCRUDController::user_get_pool_waitlist_handler))
+    .route("/id/{id}/waitlist/me", get(PoolCRUDController::user_get_my_waitlist_position_handler))
+    .route("/id/{id}/waitlist/{account_id}", delete(PoolCRUDController::user_delete_from_waitlist_handler))
+    .route("/id/{id}/waitlist/{account_id}/promote", post(PoolCRUDController::user_promote_from_waitlist_handler))
+    .route("/id/{id}/organizers", get(PoolCRUDController::user_get_pool_organizers_handler))
+    .route("/id/{id}/organizers", post(PoolCRUDController::user_add_pool_organizer_handler))
+    .route("/id/{id}/organizers/{account_id}", delete(PoolCRUDController::user_delete_pool_organizer_handler))
+    .route("/id/{id}/transfer_ownership", post(PoolCRUDController::user_transfer_pool_ownership_handler))
+    .route("/id/{id}/clone", post(PoolCRUDController::user_clone_pool_handler))
+    .route("/id/{id}/invitations", get(PoolCRUDController::user_get_pool_invitations_handler))
+    .route("/invitations", get(PoolCRUDController::user_get_my_pool_invitations_handler))
+    .route("/invitations/{invitation_id}/accept", post(PoolCRUDController::user_accept_pool_invitation_handler))
+    .route("/invitations/{invitation_id}", delete(PoolCRUDController::user_decline_pool_invitation_handler))
+    .route("/join/{token}", get(PoolCRUDController::user_get_pool_by_join_token_handler))
+    .route("/join/{token}", post(PoolCRUDController::user_join_pool_by_token_handler));
     return PoolCRUDController::objects_router(state)
     .merge(router);
 }
\ No newline at end of file
@@ -1,8 +1,9 @@
 use axum::{body::Body, extract::{Path, Request, State}, http::{HeaderMap, StatusCode}, response::IntoResponse, routing::{delete, get, post, put}, Json, Router};
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use sqlx::sqlite::SqliteRow;
 
-use crate::{core::{controllers::{ApiResponse, ICRUDController, WhoIsExecutor}, data_model::traits::{IAccountRelated, ILocalObject}, services::{escape_string, IDbService, SQLiteDbService}}, santa::{data_model::{enums::PoolState, implementations::{Pool, Room}, traits::{IPool, IPoolRelated, IRoom}}, services::{get_room_by_id, get_rooms_by_account_id, row_to_member, row_to_pool, row_to_room, user_create_room_for_members, user_get_last_messages_by_room_id, user_get_room_info_by_id, user_get_rooms_by_user, user_send_message_to_room2}}, AppState};
+use crate::{core::{controllers::{ApiResponse, ICRUDController, WhoIsExecutor}, data_model::traits::ILocalObject, services::{escape_string, IDbService, SQLiteDbService}}, santa::{data_model::{enums::{PoolState, RoomState}, implementations::{Pool, Room}, traits::{IPool, IPoolRelated, IRoom}}, services::{user_get_gift_spend, user_set_gift_spend, is_pool_organizer, get_room_by_id, get_rooms_by_account_id, row_to_member, row_to_pool, row_to_room, user_change_room_state, user_create_room_for_members, user_get_last_messages_by_room_id, user_get_recipient_address, user_get_room_info_by_id, user_get_room_timeline, user_get_rooms_by_user, user_get_thank_you_note, user_send_message_to_room2, user_set_room_shipment, user_set_thank_you_note}}, AppState};
 
 #[derive(Serialize, Deserialize)]
 pub struct CreateRoomRequestData {
@@ -15,6 +16,30 @@ pub struct UserSendMessageRequest {
     pub text_content : String
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct ChangeRoomStateRequestData {
+    pub room_state : RoomState
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SetThankYouNoteRequestData {
+    pub text_content : String,
+    pub photo : Option<String>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SetShipmentRequestData {
+    pub carrier_name : String,
+    pub tracking_number : String,
+    pub expected_delivery_date : Option<NaiveDate>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SetGiftSpendRequestData {
+    pub amount : u64,
+    pub currency : Option<String>
+}
+
 pub struct RoomCRUDController {}
 
 impl RoomCRUDController {
@@ -41,22 +66,14 @@ impl RoomCRUDController {
         if room_opt.is_none() { return (Some(true), WhoIsExecutor::NoMatter); }
         let room = room_opt.unwrap();
 
-        let mailer_opt = db_service.get_one_by_prop("members", "mailer_id", room.mailer_id(), row_to_member).await;
-        if mailer_opt.is_none() { return (Some(false), WhoIsExecutor::NoMatter); }
-        let mailer = mailer_opt.unwrap();
-
-        let recipient_opt = db_service.get_one_by_prop("members", "recipient_id", room.recipient_id(), row_to_member).await;
-        if recipient_opt.is_none() { return (Some(false), WhoIsExecutor::NoMatter); }
-        let recipient = recipient_opt.unwrap();
-        
-        let is_resource_owner = mailer.account_id() == executor_id || recipient.account_id() == executor_id;
+        let is_resource_owner = room.mailer_id() == executor_id || room.recipient_id() == executor_id;
         if is_resource_owner { return (None, WhoIsExecutor::ResourceOwner); }
 
         let pool_opt = db_service.get_one_by_prop("pools", "id", room.pool_id(), row_to_pool).await;
         if pool_opt.is_none() { return (Some(false), WhoIsExecutor::NoMatter); }
         let pool = pool_opt.unwrap();
 
-        let is_pool_owner = pool.account_id() == executor_id;
+        let is_pool_owner = is_pool_organizer(pool.id(), executor_id, state).await;
         if is_pool_owner { return (None, WhoIsExecutor::PoolOwner); }
 
         return (None, WhoIsExecutor::Other);
@@ -120,6 +137,60 @@ impl RoomCRUDController {
         }
     }
 
+    async fn user_get_room_timeline_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let esc_room_id_string = escape_string(id.as_str());
+        let room_id = esc_room_id_string.as_str();
+        
+        let has_access_check = Self::user_has_access_to_room(room_id, executor_id, &state).await;
+        if has_access_check.is_some() {
+            return has_access_check.unwrap().into_response();
+        } 
+
+        let resp = user_get_room_timeline(room_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    async fn user_get_thank_you_note_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let esc_room_id_string = escape_string(id.as_str());
+        let room_id = esc_room_id_string.as_str();
+        
+        let has_access_check = Self::user_has_access_to_room(room_id, executor_id, &state).await;
+        if has_access_check.is_some() {
+            return has_access_check.unwrap().into_response();
+        } 
+
+        let resp = user_get_thank_you_note(room_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    async fn user_get_recipient_address_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let esc_room_id_string = escape_string(id.as_str());
+        let room_id = esc_room_id_string.as_str();
+        
+        let has_access_check = Self::user_has_access_to_room(room_id, executor_id, &state).await;
+        if has_access_check.is_some() {
+            return has_access_check.unwrap().into_response();
+        } 
+
+        let resp = user_get_recipient_address(room_id, executor_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
     async fn user_get_last_messages_by_room_id_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
         const LIMIT : usize = 64;
 
@@ -160,6 +231,98 @@ impl RoomCRUDController {
         }
 
     }
+
+    async fn user_change_room_state_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<ChangeRoomStateRequestData>) -> impl IntoResponse {
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let esc_room_id_string = escape_string(id.as_str());
+        let room_id = esc_room_id_string.as_str();
+
+        // admins and moderators make the next step for either side, the order of states is kept for them too
+        let (basic_check, _) = Self::basic_check_perm(&state, executor_id).await;
+        let is_moderator = basic_check.is_some_and(|b| {b});
+        if !is_moderator {
+            let has_access_check = Self::user_has_access_to_room(room_id, executor_id, &state).await;
+            if has_access_check.is_some() {
+                return has_access_check.unwrap().into_response();
+            }
+        }
+
+        let resp = user_change_room_state(room_id, executor_id, json.room_state, is_moderator, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    async fn user_set_room_shipment_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<SetShipmentRequestData>) -> impl IntoResponse {
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let esc_room_id_string = escape_string(id.as_str());
+        let room_id = esc_room_id_string.as_str();
+
+        let has_access_check = Self::user_has_access_to_room(room_id, executor_id, &state).await;
+        if has_access_check.is_some() {
+            return has_access_check.unwrap().into_response();
+        }
+
+        let resp = user_set_room_shipment(room_id, executor_id, json.carrier_name.as_str(), json.tracking_number.as_str(), json.expected_delivery_date, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    async fn user_get_gift_spend_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, _request : Request<Body>) -> impl IntoResponse {
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let esc_room_id_string = escape_string(id.as_str());
+        let room_id = esc_room_id_string.as_str();
+
+        // pool organizers can see spends of rooms they are not part of
+        let resp = user_get_gift_spend(room_id, executor_id, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    async fn user_set_gift_spend_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<SetGiftSpendRequestData>) -> impl IntoResponse {
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let esc_room_id_string = escape_string(id.as_str());
+        let room_id = esc_room_id_string.as_str();
+
+        let has_access_check = Self::user_has_access_to_room(room_id, executor_id, &state).await;
+        if has_access_check.is_some() {
+            return has_access_check.unwrap().into_response();
+        }
+
+        let resp = user_set_gift_spend(room_id, executor_id, json.amount, json.currency, &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
+
+    async fn user_set_thank_you_note_handler(State(state) : State<AppState>, Path(id) : Path<String>, headers : HeaderMap, Json(json) : Json<SetThankYouNoteRequestData>) -> impl IntoResponse {
+        let executor_id = headers.get("account_id").unwrap().to_str().unwrap();
+        let esc_room_id_string = escape_string(id.as_str());
+        let room_id = esc_room_id_string.as_str();
+
+        let has_access_check = Self::user_has_access_to_room(room_id, executor_id, &state).await;
+        if has_access_check.is_some() {
+            return has_access_check.unwrap().into_response();
+        }
+
+        let photo = json.photo.unwrap_or_default();
+        let resp = user_set_thank_you_note(room_id, executor_id, json.text_content.as_str(), photo.as_str(), &state).await;
+        if resp.is_ok() {
+            return (StatusCode::OK, Json(resp)).into_response();
+        } else {
+            return (StatusCode::BAD_REQUEST, Json(resp)).into_response();
+        }
+    }
 }
 
 impl ICRUDController<CreateRoomRequestData, Room> for RoomCRUDController {
@@ -208,8 +371,8 @@ impl ICRUDController<CreateRoomRequestData, Room> for RoomCRUDController {
         }
         let pool = pool_opt.unwrap();
 
-        if pool.account_id() != executor_id {
-            let err_msg = format!("Only pool owner can create rooms in pool");
+        if !is_pool_organizer(pool_id, executor_id, state).await {
+            let err_msg = format!("Only pool organizers can create rooms in pool");
             return ApiResponse::error_from_str(err_msg.as_str()); 
         }
 
@@ -250,35 +413,22 @@ impl ICRUDController<CreateRoomRequestData, Room> for RoomCRUDController {
         return None;
     }
     
-    async fn check_perm_update(state : &AppState, executor_id : &str, object_id : &str) -> bool {
-        let (basic_check, role) = Self::basic_check_owner(state, executor_id, object_id).await;
-        if basic_check.is_some() {return basic_check.unwrap(); }
-        if role == WhoIsExecutor::Other { return false; }
-
-        let pool_opt = Self::get_pool_by_room_id(state, object_id).await;
-        if pool_opt.is_none() { return false; }
-        let pool = pool_opt.unwrap();
-
-        if role == WhoIsExecutor::ResourceOwner && pool.state() == PoolState::Started {
-            return true;
-        }
-        
+    async fn check_perm_update(_state : &AppState, _executor_id : &str, _object_id : &str) -> bool {
+        // nobody edits rooms directly, admins and moderators included: the state changes only through change_state,
+        // which checks the order of states and records every step
         return false;
     }
     
     async fn check_perm_delete(state : &AppState, executor_id : &str, object_id : &str) -> bool {
-        let (basic_check, role) = Self::basic_check_owner(state, executor_id, object_id).await;
-        if basic_check.is_some() {return basic_check.unwrap(); }
-        if role == WhoIsExecutor::Other { return false; }
-
         let pool_opt = Self::get_pool_by_room_id(state, object_id).await;
         if pool_opt.is_none() { return false; }
         let pool = pool_opt.unwrap();
 
-        if role == WhoIsExecutor::PoolOwner && pool.state() == PoolState::Ended {
-            return true;
-        } 
-        
+        // rooms of an ended or archived pool are the draw history the archive keeps
+        if pool.state() == PoolState::Ended || pool.state() == PoolState::Archived { return false; }
+
+        let (basic_check, _) = Self::basic_check_owner(state, executor_id, object_id).await;
+        if basic_check.is_some() {return basic_check.unwrap(); }
         return false;
     }
 }
@@ -288,7 +438,15 @@ pub fn room_router(state : &AppState) -> Router<AppState> {
     .route("/my_rooms", get(RoomCRUDController::user_get_rooms_handler))
     .route("/id/{id}/info", get(RoomCRUDController::user_get_room_info_handler))
     .route("/id/{id}/last_messages", get(RoomCRUDController::user_get_last_messages_by_room_id_handler))
-    .route("/id/{id}/send_message", post(RoomCRUDController::user_send_message_to_room_handler));
+    .route("/id/{id}/send_message", post(RoomCRUDController::user_send_message_to_room_handler))
+    .route("/id/{id}/change_state", post(RoomCRUDController::user_change_room_state_handler))
+    .route("/id/{id}/timeline", get(RoomCRUDController::user_get_room_timeline_handler))
+    .route("/id/{id}/shipment", put(RoomCRUDController::user_set_room_shipment_handler))
+    .route("/id/{id}/recipient_address", get(RoomCRUDController::user_get_recipient_address_handler))
+    .route("/id/{id}/thank_you_note", get(RoomCRUDController::user_get_thank_you_note_handler))
+    .route("/id/{id}/thank_you_note", put(RoomCRUDController::user_set_thank_you_note_handler))
+    .route("/id/{id}/gift_spend", get(RoomCRUDController::user_get_gift_spend_handler))
+    .route("/id/{id}/gift_spend", put(RoomCRUDController::user_set_gift_spend_handler));
     return RoomCRUDController::objects_router(state)
     .merge(router);
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 mod pool_state;
 mod room_state;
+mod pool_visibility;
 
 pub use pool_state::PoolState;
-pub use room_state::RoomState;
\ No newline at end of file
+pub use room_state::RoomState;
+pub use pool_visibility::PoolVisibility;
\ No newline at end of file
@@ -8,7 +8,8 @@ pub enum PoolState {
     Open = 1,
     Pooling = 2,
     Started = 3,
-    Ended = 4
+    Ended = 4,
+    Archived = 5
 }
 
 impl TryFrom<usize> for PoolState {
@@ -21,6 +22,7 @@ impl TryFrom<usize> for PoolState {
             value if value == PoolState::Pooling as usize => Ok(PoolState::Pooling),
             value if value == PoolState::Started as usize => Ok(PoolState::Started),
             value if value == PoolState::Ended as usize => Ok(PoolState::Ended),
+            value if value == PoolState::Archived as usize => Ok(PoolState::Archived),
             _ => Err(())
         }
     }
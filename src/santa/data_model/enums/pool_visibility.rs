@@ -0,0 +1,23 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+
+#[derive(Serialize_repr, Deserialize_repr, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PoolVisibility {
+    Public = 0,
+    Unlisted = 1,
+    Private = 2
+}
+
+impl TryFrom<usize> for PoolVisibility {
+    type Error = ();
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            value if value == PoolVisibility::Public as usize => Ok(PoolVisibility::Public),
+            value if value == PoolVisibility::Unlisted as usize => Ok(PoolVisibility::Unlisted),
+            value if value == PoolVisibility::Private as usize => Ok(PoolVisibility::Private),
+            _ => Err(())
+        }
+    }
+}
\ No newline at end of file
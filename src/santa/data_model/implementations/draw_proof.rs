@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::ILocalObject;
+use crate::santa::data_model::traits::{IDrawProof, IPoolRelated};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DrawProof {
+    id : String,
+    pool_id : String,
+    seed : String,
+    commitment : String,
+    members : String,
+    forbidden_pairs : String,
+    history_pairs : String,
+    pairs : String,
+    creation_date : DateTime<Utc>
+}
+
+impl ILocalObject for DrawProof {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IPoolRelated for DrawProof {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IDrawProof for DrawProof {
+    fn new(id : &str, pool_id : &str, seed : &str, commitment : &str, members : &str, forbidden_pairs : &str, history_pairs : &str, pairs : &str, creation_date : DateTime<Utc>) -> Self {
+        return DrawProof {
+            id: String::from(id),
+            pool_id: String::from(pool_id),
+            seed: String::from(seed),
+            commitment: String::from(commitment),
+            members: String::from(members),
+            forbidden_pairs: String::from(forbidden_pairs),
+            history_pairs: String::from(history_pairs),
+            pairs: String::from(pairs),
+            creation_date: creation_date
+        };
+    }
+
+    fn seed(&self) -> &str { self.seed.as_str() }
+
+    fn commitment(&self) -> &str { self.commitment.as_str() }
+
+    fn members(&self) -> &str { self.members.as_str() }
+
+    fn forbidden_pairs(&self) -> &str { self.forbidden_pairs.as_str() }
+
+    fn history_pairs(&self) -> &str { self.history_pairs.as_str() }
+
+    fn pairs(&self) -> &str { self.pairs.as_str() }
+
+    fn creation_date(&self) -> DateTime<Utc> { self.creation_date }
+
+    fn set_seed(&mut self, seed : &str) -> () { self.seed = String::from(seed); }
+
+    fn set_commitment(&mut self, commitment : &str) -> () { self.commitment = String::from(commitment); }
+
+    fn set_members(&mut self, members : &str) -> () { self.members = String::from(members); }
+
+    fn set_forbidden_pairs(&mut self, forbidden_pairs : &str) -> () { self.forbidden_pairs = String::from(forbidden_pairs); }
+
+    fn set_history_pairs(&mut self, history_pairs : &str) -> () { self.history_pairs = String::from(history_pairs); }
+
+    fn set_pairs(&mut self, pairs : &str) -> () { self.pairs = String::from(pairs); }
+
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> () { self.creation_date = creation_date; }
+}
\ No newline at end of file
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::{IAccountRelated, ILocalObject};
+use crate::santa::data_model::traits::{IExclusion, IPoolRelated};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Exclusion {
+    id : String,
+    account_id : String,
+    pool_id : String,
+    first_account_id : String,
+    second_account_id : String
+}
+
+impl ILocalObject for Exclusion {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IAccountRelated for Exclusion {
+    fn account_id(&self) -> &str { self.account_id.as_str() }
+
+    fn set_account_id(&mut self, account_id : &str) -> () { self.account_id = String::from(account_id); }
+}
+
+impl IPoolRelated for Exclusion {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IExclusion for Exclusion {
+    fn new(id : &str, account_id : &str, pool_id : &str, first_account_id : &str, second_account_id : &str) -> Self {
+        return Exclusion {
+            id: String::from(id),
+            account_id: String::from(account_id),
+            pool_id: String::from(pool_id),
+            first_account_id: String::from(first_account_id),
+            second_account_id: String::from(second_account_id)
+        };
+    }
+
+    fn first_account_id(&self) -> &str { self.first_account_id.as_str() }
+
+    fn second_account_id(&self) -> &str { self.second_account_id.as_str() }
+
+    fn set_first_account_id(&mut self, first_account_id : &str) -> () { self.first_account_id = String::from(first_account_id); }
+
+    fn set_second_account_id(&mut self, second_account_id : &str) -> () { self.second_account_id = String::from(second_account_id); }
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::ILocalObject;
+use crate::santa::data_model::traits::{IGiftSpend, IPoolRelated, IRoomRelated};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GiftSpend {
+    id : String,
+    room_id : String,
+    pool_id : String,
+    amount : u64,
+    currency : String,
+    creation_date : DateTime<Utc>
+}
+
+impl ILocalObject for GiftSpend {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IRoomRelated for GiftSpend {
+    fn room_id(&self) -> &str { self.room_id.as_str() }
+
+    fn set_room_id(&mut self, room_id : &str) -> () { self.room_id = String::from(room_id); }
+}
+
+impl IPoolRelated for GiftSpend {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IGiftSpend for GiftSpend {
+    fn new(id : &str, room_id : &str, pool_id : &str, amount : u64, currency : &str, creation_date : DateTime<Utc>) -> Self {
+        return GiftSpend {
+            id: String::from(id),
+            room_id: String::from(room_id),
+            pool_id: String::from(pool_id),
+            amount: amount,
+            currency: String::from(currency),
+            creation_date: creation_date
+        };
+    }
+
+    fn amount(&self) -> u64 { self.amount }
+
+    fn currency(&self) -> &str { self.currency.as_str() }
+
+    fn creation_date(&self) -> DateTime<Utc> { self.creation_date }
+
+    fn set_amount(&mut self, amount : u64) -> () { self.amount = amount; }
+
+    fn set_currency(&mut self, currency : &str) -> () { self.currency = String::from(currency); }
+
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> () { self.creation_date = creation_date; }
+}
\ No newline at end of file
@@ -1,15 +1,15 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::data_model::traits::{IAccountRelated, ILocalObject};
-use crate::santa::data_model::traits::{IMember, IPoolRelated, IRoomRelated};
+use crate::santa::data_model::traits::{IMember, IPoolRelated};
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct Member {
     id : String,
     account_id : String,
-    room_id : String,
     pool_id : String,
-    wishlist : String
+    wishlist : String,
+    group_name : String
 }
 
 impl ILocalObject for Member {
@@ -24,12 +24,6 @@ impl IAccountRelated for Member {
     fn set_account_id(&mut self, account_id : &str) -> () { self.account_id = String::from(account_id); }
 }
 
-impl IRoomRelated for Member {
-    fn room_id(&self) -> &str { self.room_id.as_str() }
-
-    fn set_room_id(&mut self, room_id : &str) -> () {  self.room_id = String::from(room_id); }
-}
-
 impl IPoolRelated for Member {
     fn pool_id(&self) -> &str { self.pool_id.as_str() }
 
@@ -37,17 +31,21 @@ impl IPoolRelated for Member {
 }
 
 impl IMember for Member {
-    fn new(id : &str, account_id : &str, room_id : &str, pool_id : &str, wishlist : &str) -> Self {
+    fn new(id : &str, account_id : &str, pool_id : &str, wishlist : &str, group_name : &str) -> Self {
         return Member {
             id: String::from(id),
             account_id: String::from(account_id),
-            room_id: String::from(room_id),
             pool_id: String::from(pool_id),
             wishlist: String::from(wishlist),
+            group_name: String::from(group_name)
         };
     }
 
     fn wishlist(&self) -> &str { self.wishlist.as_str() }
 
+    fn group_name(&self) -> &str { self.group_name.as_str() }
+
     fn set_wishlist(&mut self, wishlist : &str) -> () { self.wishlist = String::from(wishlist); }
+
+    fn set_group_name(&mut self, group_name : &str) -> () { self.group_name = String::from(group_name); }
 }
\ No newline at end of file
@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::{IAccountRelated, ILocalObject};
+use crate::santa::data_model::traits::{IMemberAddress, IPoolRelated};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MemberAddress {
+    id : String,
+    pool_id : String,
+    account_id : String,
+    encrypted_address : String,
+    last_update_date : DateTime<Utc>
+}
+
+impl ILocalObject for MemberAddress {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IAccountRelated for MemberAddress {
+    fn account_id(&self) -> &str { self.account_id.as_str() }
+
+    fn set_account_id(&mut self, account_id : &str) -> () { self.account_id = String::from(account_id); }
+}
+
+impl IPoolRelated for MemberAddress {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IMemberAddress for MemberAddress {
+    fn new(id : &str, pool_id : &str, account_id : &str, encrypted_address : &str, last_update_date : DateTime<Utc>) -> Self {
+        return MemberAddress {
+            id: String::from(id),
+            pool_id: String::from(pool_id),
+            account_id: String::from(account_id),
+            encrypted_address: String::from(encrypted_address),
+            last_update_date: last_update_date
+        };
+    }
+
+    fn encrypted_address(&self) -> &str { self.encrypted_address.as_str() }
+
+    fn last_update_date(&self) -> DateTime<Utc> { self.last_update_date }
+
+    fn set_encrypted_address(&mut self, encrypted_address : &str) -> () { self.encrypted_address = String::from(encrypted_address); }
+
+    fn set_last_update_date(&mut self, last_update_date : DateTime<Utc>) -> () { self.last_update_date = last_update_date; }
+}
\ No newline at end of file
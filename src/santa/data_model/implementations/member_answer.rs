@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::{IAccountRelated, ILocalObject};
+use crate::santa::data_model::traits::{IMemberAnswer, IPoolRelated};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct MemberAnswer {
+    id : String,
+    pool_id : String,
+    account_id : String,
+    question_id : String,
+    text_content : String
+}
+
+impl ILocalObject for MemberAnswer {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IAccountRelated for MemberAnswer {
+    fn account_id(&self) -> &str { self.account_id.as_str() }
+
+    fn set_account_id(&mut self, account_id : &str) -> () { self.account_id = String::from(account_id) }
+}
+
+impl IPoolRelated for MemberAnswer {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IMemberAnswer for MemberAnswer {
+    fn new(id : &str, pool_id : &str, account_id : &str, question_id : &str, text_content : &str) -> Self {
+        return MemberAnswer {
+            id: String::from(id),
+            pool_id: String::from(pool_id),
+            account_id: String::from(account_id),
+            question_id: String::from(question_id),
+            text_content: String::from(text_content)
+        };
+    }
+
+    fn question_id(&self) -> &str { self.question_id.as_str() }
+
+    fn text_content(&self) -> &str { self.text_content.as_str() }
+
+    fn set_question_id(&mut self, question_id : &str) -> () { self.question_id = String::from(question_id); }
+
+    fn set_text_content(&mut self, text_content : &str) -> () { self.text_content = String::from(text_content); }
+}
\ No newline at end of file
@@ -2,8 +2,38 @@ mod pool;
 mod room;
 mod message;
 mod member;
+mod exclusion;
+mod draw_proof;
+mod room_state_change;
+mod shipment;
+mod member_address;
+mod thank_you_note;
+mod stage_deadline;
+mod pool_join_token;
+mod waitlist_entry;
+mod pool_invitation;
+mod pool_organizer;
+mod gift_spend;
+mod pool_announcement;
+mod pool_question;
+mod member_answer;
 
 pub use pool::Pool;
 pub use room::Room;
 pub use message::Message;
-pub use member::Member;
\ No newline at end of file
+pub use member::Member;
+pub use exclusion::Exclusion;
+pub use draw_proof::DrawProof;
+pub use room_state_change::RoomStateChange;
+pub use shipment::Shipment;
+pub use member_address::MemberAddress;
+pub use thank_you_note::ThankYouNote;
+pub use stage_deadline::StageDeadline;
+pub use pool_join_token::PoolJoinToken;
+pub use waitlist_entry::WaitlistEntry;
+pub use pool_invitation::PoolInvitation;
+pub use pool_organizer::PoolOrganizer;
+pub use gift_spend::GiftSpend;
+pub use pool_announcement::PoolAnnouncement;
+pub use pool_question::PoolQuestion;
+pub use member_answer::MemberAnswer;
\ No newline at end of file
@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::core::data_model::traits::{IAccountRelated, ILocalObject};
-use crate::santa::data_model::enums::PoolState;
+use crate::santa::data_model::enums::{PoolState, PoolVisibility, RoomState};
 use crate::santa::data_model::traits::IPool;
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -15,8 +15,20 @@ pub struct Pool {
     max_price : u64,
     creation_date : DateTime<Utc>,
     lifetime : u64,
-    pool_state : PoolState
-
+    pool_state : PoolState,
+    previous_pool_id : String,
+    history_depth : u64,
+    gifts_per_member : u64,
+    cross_group_draw : bool,
+    end_room_state : RoomState,
+    open_at : Option<DateTime<Utc>>,
+    draw_at : Option<DateTime<Utc>>,
+    end_at : Option<DateTime<Utc>>,
+    visibility : PoolVisibility,
+    max_members : u64,
+    currency : String,
+    archive_date : Option<DateTime<Utc>>,
+    state_change_date : DateTime<Utc>
 }
 
 impl ILocalObject for Pool {
@@ -32,7 +44,7 @@ impl IAccountRelated for Pool {
 }
 
 impl IPool for Pool {
-    fn new(id : &str, name : &str, description : &str, account_id : &str, min_price : u64, max_price : u64, creation_date : DateTime<Utc>, lifetime : u64, pool_state : PoolState) -> Self {
+    fn new(id : &str, name : &str, description : &str, account_id : &str, min_price : u64, max_price : u64, creation_date : DateTime<Utc>, lifetime : u64, pool_state : PoolState, previous_pool_id : &str, history_depth : u64, gifts_per_member : u64, cross_group_draw : bool, end_room_state : RoomState, open_at : Option<DateTime<Utc>>, draw_at : Option<DateTime<Utc>>, end_at : Option<DateTime<Utc>>, visibility : PoolVisibility, max_members : u64, currency : &str, archive_date : Option<DateTime<Utc>>, state_change_date : DateTime<Utc>) -> Self {
         return Pool {
             id: String::from(id),
             name: String::from(name),
@@ -42,7 +54,20 @@ impl IPool for Pool {
             max_price: max_price,
             creation_date: creation_date,
             lifetime: lifetime,
-            pool_state: pool_state
+            pool_state: pool_state,
+            previous_pool_id: String::from(previous_pool_id),
+            history_depth: history_depth,
+            gifts_per_member: gifts_per_member,
+            cross_group_draw: cross_group_draw,
+            end_room_state: end_room_state,
+            open_at: open_at,
+            draw_at: draw_at,
+            end_at: end_at,
+            visibility: visibility,
+            max_members: max_members,
+            currency: String::from(currency),
+            archive_date: archive_date,
+            state_change_date: state_change_date
         };
     }
 
@@ -60,6 +85,32 @@ impl IPool for Pool {
 
     fn state(&self) -> PoolState { self.pool_state.clone() }
 
+    fn previous_pool_id(&self) -> &str { self.previous_pool_id.as_str() }
+
+    fn history_depth(&self) -> u64 { self.history_depth }
+
+    fn gifts_per_member(&self) -> u64 { self.gifts_per_member }
+
+    fn cross_group_draw(&self) -> bool { self.cross_group_draw }
+
+    fn end_room_state(&self) -> RoomState { self.end_room_state.clone() }
+
+    fn open_at(&self) -> Option<DateTime<Utc>> { self.open_at }
+
+    fn draw_at(&self) -> Option<DateTime<Utc>> { self.draw_at }
+
+    fn end_at(&self) -> Option<DateTime<Utc>> { self.end_at }
+
+    fn visibility(&self) -> PoolVisibility { self.visibility.clone() }
+
+    fn max_members(&self) -> u64 { self.max_members }
+
+    fn currency(&self) -> &str { self.currency.as_str() }
+
+    fn archive_date(&self) -> Option<DateTime<Utc>> { self.archive_date }
+
+    fn state_change_date(&self) -> DateTime<Utc> { self.state_change_date }
+
     fn set_name(&mut self, name : &str) -> () { self.name = String::from(name); }
 
     fn set_description(&mut self, description : &str) -> () { self.description = String::from(description); }
@@ -73,4 +124,30 @@ impl IPool for Pool {
     fn set_lifetime(&mut self, lifetime : u64) -> () { self.lifetime = lifetime; }
 
     fn set_state(&mut self, pool_state : PoolState) -> () { self.pool_state = pool_state; }
+
+    fn set_previous_pool_id(&mut self, previous_pool_id : &str) -> () { self.previous_pool_id = String::from(previous_pool_id); }
+
+    fn set_history_depth(&mut self, history_depth : u64) -> () { self.history_depth = history_depth; }
+
+    fn set_gifts_per_member(&mut self, gifts_per_member : u64) -> () { self.gifts_per_member = gifts_per_member; }
+
+    fn set_cross_group_draw(&mut self, cross_group_draw : bool) -> () { self.cross_group_draw = cross_group_draw; }
+
+    fn set_end_room_state(&mut self, end_room_state : RoomState) -> () { self.end_room_state = end_room_state; }
+
+    fn set_open_at(&mut self, open_at : Option<DateTime<Utc>>) -> () { self.open_at = open_at; }
+
+    fn set_draw_at(&mut self, draw_at : Option<DateTime<Utc>>) -> () { self.draw_at = draw_at; }
+
+    fn set_end_at(&mut self, end_at : Option<DateTime<Utc>>) -> () { self.end_at = end_at; }
+
+    fn set_visibility(&mut self, visibility : PoolVisibility) -> () { self.visibility = visibility; }
+
+    fn set_max_members(&mut self, max_members : u64) -> () { self.max_members = max_members; }
+
+    fn set_currency(&mut self, currency : &str) -> () { self.currency = String::from(currency); }
+
+    fn set_archive_date(&mut self, archive_date : Option<DateTime<Utc>>) -> () { self.archive_date = archive_date; }
+
+    fn set_state_change_date(&mut self, state_change_date : DateTime<Utc>) -> () { self.state_change_date = state_change_date; }
 }
\ No newline at end of file
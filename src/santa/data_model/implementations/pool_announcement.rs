@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::{IAccountRelated, ILocalObject};
+use crate::santa::data_model::traits::{IPoolAnnouncement, IPoolRelated};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PoolAnnouncement {
+    id : String,
+    pool_id : String,
+    account_id : String,
+    text_content : String,
+    is_pinned : bool,
+    creation_date : DateTime<Utc>,
+    last_update_date : DateTime<Utc>
+}
+
+impl ILocalObject for PoolAnnouncement {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IAccountRelated for PoolAnnouncement {
+    fn account_id(&self) -> &str { self.account_id.as_str() }
+
+    fn set_account_id(&mut self, account_id : &str) -> () { self.account_id = String::from(account_id) }
+}
+
+impl IPoolRelated for PoolAnnouncement {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IPoolAnnouncement for PoolAnnouncement {
+    fn new(id : &str, pool_id : &str, account_id : &str, text_content : &str, is_pinned : bool, creation_date : DateTime<Utc>, last_update_date : DateTime<Utc>) -> Self {
+        return PoolAnnouncement {
+            id: String::from(id),
+            pool_id: String::from(pool_id),
+            account_id: String::from(account_id),
+            text_content: String::from(text_content),
+            is_pinned: is_pinned,
+            creation_date: creation_date,
+            last_update_date: last_update_date
+        };
+    }
+
+    fn text_content(&self) -> &str { self.text_content.as_str() }
+
+    fn is_pinned(&self) -> bool { self.is_pinned }
+
+    fn creation_date(&self) -> DateTime<Utc> { self.creation_date }
+
+    fn last_update_date(&self) -> DateTime<Utc> { self.last_update_date }
+
+    fn set_text_content(&mut self, text_content : &str) -> () { self.text_content = String::from(text_content); }
+
+    fn set_is_pinned(&mut self, is_pinned : bool) -> () { self.is_pinned = is_pinned; }
+
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> () { self.creation_date = creation_date; }
+
+    fn set_last_update_date(&mut self, last_update_date : DateTime<Utc>) -> () { self.last_update_date = last_update_date; }
+}
\ No newline at end of file
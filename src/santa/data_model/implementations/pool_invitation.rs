@@ -0,0 +1,46 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::{IAccountRelated, ILocalObject};
+use crate::santa::data_model::traits::{IPoolInvitation, IPoolRelated};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PoolInvitation {
+    id : String,
+    pool_id : String,
+    account_id : String,
+    creation_date : DateTime<Utc>
+}
+
+impl ILocalObject for PoolInvitation {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IAccountRelated for PoolInvitation {
+    fn account_id(&self) -> &str { self.account_id.as_str() }
+
+    fn set_account_id(&mut self, account_id : &str) -> () { self.account_id = String::from(account_id) }
+}
+
+impl IPoolRelated for PoolInvitation {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IPoolInvitation for PoolInvitation {
+    fn new(id : &str, pool_id : &str, account_id : &str, creation_date : DateTime<Utc>) -> Self {
+        return PoolInvitation {
+            id: String::from(id),
+            pool_id: String::from(pool_id),
+            account_id: String::from(account_id),
+            creation_date: creation_date
+        };
+    }
+
+    fn creation_date(&self) -> DateTime<Utc> { self.creation_date }
+
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> () { self.creation_date = creation_date; }
+}
\ No newline at end of file
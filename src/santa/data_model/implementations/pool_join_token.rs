@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::ILocalObject;
+use crate::santa::data_model::traits::{IPoolJoinToken, IPoolRelated};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PoolJoinToken {
+    id : String,
+    pool_id : String,
+    token : String,
+    max_uses : u64,
+    uses_count : u64,
+    is_revoked : bool,
+    creation_date : DateTime<Utc>
+}
+
+impl ILocalObject for PoolJoinToken {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IPoolRelated for PoolJoinToken {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IPoolJoinToken for PoolJoinToken {
+    fn new(id : &str, pool_id : &str, token : &str, max_uses : u64, uses_count : u64, is_revoked : bool, creation_date : DateTime<Utc>) -> Self {
+        return PoolJoinToken {
+            id: String::from(id),
+            pool_id: String::from(pool_id),
+            token: String::from(token),
+            max_uses: max_uses,
+            uses_count: uses_count,
+            is_revoked: is_revoked,
+            creation_date: creation_date
+        };
+    }
+
+    fn token(&self) -> &str { self.token.as_str() }
+
+    fn max_uses(&self) -> u64 { self.max_uses }
+
+    fn uses_count(&self) -> u64 { self.uses_count }
+
+    fn is_revoked(&self) -> bool { self.is_revoked }
+
+    fn creation_date(&self) -> DateTime<Utc> { self.creation_date }
+
+    fn set_token(&mut self, token : &str) -> () { self.token = String::from(token); }
+
+    fn set_max_uses(&mut self, max_uses : u64) -> () { self.max_uses = max_uses; }
+
+    fn set_uses_count(&mut self, uses_count : u64) -> () { self.uses_count = uses_count; }
+
+    fn set_is_revoked(&mut self, is_revoked : bool) -> () { self.is_revoked = is_revoked; }
+
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> () { self.creation_date = creation_date; }
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::ILocalObject;
+use crate::santa::data_model::traits::{IPoolQuestion, IPoolRelated};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PoolQuestion {
+    id : String,
+    pool_id : String,
+    text_content : String,
+    is_required : bool,
+    position : u64
+}
+
+impl ILocalObject for PoolQuestion {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IPoolRelated for PoolQuestion {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IPoolQuestion for PoolQuestion {
+    fn new(id : &str, pool_id : &str, text_content : &str, is_required : bool, position : u64) -> Self {
+        return PoolQuestion {
+            id: String::from(id),
+            pool_id: String::from(pool_id),
+            text_content: String::from(text_content),
+            is_required: is_required,
+            position: position
+        };
+    }
+
+    fn text_content(&self) -> &str { self.text_content.as_str() }
+
+    fn is_required(&self) -> bool { self.is_required }
+
+    fn position(&self) -> u64 { self.position }
+
+    fn set_text_content(&mut self, text_content : &str) -> () { self.text_content = String::from(text_content); }
+
+    fn set_is_required(&mut self, is_required : bool) -> () { self.is_required = is_required; }
+
+    fn set_position(&mut self, position : u64) -> () { self.position = position; }
+}
\ No newline at end of file
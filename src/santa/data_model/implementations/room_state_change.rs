@@ -0,0 +1,61 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::{IAccountRelated, ILocalObject};
+use crate::santa::data_model::enums::RoomState;
+use crate::santa::data_model::traits::{IPoolRelated, IRoomRelated, IRoomStateChange};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RoomStateChange {
+    id : String,
+    room_id : String,
+    pool_id : String,
+    account_id : String,
+    room_state : RoomState,
+    creation_date : DateTime<Utc>
+}
+
+impl ILocalObject for RoomStateChange {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IAccountRelated for RoomStateChange {
+    fn account_id(&self) -> &str { self.account_id.as_str() }
+
+    fn set_account_id(&mut self, account_id : &str) -> () { self.account_id = String::from(account_id); }
+}
+
+impl IRoomRelated for RoomStateChange {
+    fn room_id(&self) -> &str { self.room_id.as_str() }
+
+    fn set_room_id(&mut self, room_id : &str) -> () { self.room_id = String::from(room_id); }
+}
+
+impl IPoolRelated for RoomStateChange {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IRoomStateChange for RoomStateChange {
+    fn new(id : &str, room_id : &str, pool_id : &str, account_id : &str, room_state : RoomState, creation_date : DateTime<Utc>) -> Self {
+        return RoomStateChange {
+            id: String::from(id),
+            room_id: String::from(room_id),
+            pool_id: String::from(pool_id),
+            account_id: String::from(account_id),
+            room_state: room_state,
+            creation_date: creation_date
+        };
+    }
+
+    fn room_state(&self) -> RoomState { self.room_state.clone() }
+
+    fn creation_date(&self) -> DateTime<Utc> { self.creation_date }
+
+    fn set_room_state(&mut self, room_state : RoomState) -> () { self.room_state = room_state; }
+
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> () { self.creation_date = creation_date; }
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::ILocalObject;
+use crate::santa::data_model::traits::{IPoolRelated, IRoomRelated, IShipment};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Shipment {
+    id : String,
+    room_id : String,
+    pool_id : String,
+    carrier_name : String,
+    tracking_number : String,
+    expected_delivery_date : Option<NaiveDate>,
+    last_update_date : DateTime<Utc>
+}
+
+impl ILocalObject for Shipment {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IRoomRelated for Shipment {
+    fn room_id(&self) -> &str { self.room_id.as_str() }
+
+    fn set_room_id(&mut self, room_id : &str) -> () { self.room_id = String::from(room_id); }
+}
+
+impl IPoolRelated for Shipment {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IShipment for Shipment {
+    fn new(id : &str, room_id : &str, pool_id : &str, carrier_name : &str, tracking_number : &str, expected_delivery_date : Option<NaiveDate>, last_update_date : DateTime<Utc>) -> Self {
+        return Shipment {
+            id: String::from(id),
+            room_id: String::from(room_id),
+            pool_id: String::from(pool_id),
+            carrier_name: String::from(carrier_name),
+            tracking_number: String::from(tracking_number),
+            expected_delivery_date: expected_delivery_date,
+            last_update_date: last_update_date
+        };
+    }
+
+    fn carrier_name(&self) -> &str { self.carrier_name.as_str() }
+
+    fn tracking_number(&self) -> &str { self.tracking_number.as_str() }
+
+    fn expected_delivery_date(&self) -> Option<NaiveDate> { self.expected_delivery_date }
+
+    fn last_update_date(&self) -> DateTime<Utc> { self.last_update_date }
+
+    fn set_carrier_name(&mut self, carrier_name : &str) -> () { self.carrier_name = String::from(carrier_name); }
+
+    fn set_tracking_number(&mut self, tracking_number : &str) -> () { self.tracking_number = String::from(tracking_number); }
+
+    fn set_expected_delivery_date(&mut self, expected_delivery_date : Option<NaiveDate>) -> () { self.expected_delivery_date = expected_delivery_date; }
+
+    fn set_last_update_date(&mut self, last_update_date : DateTime<Utc>) -> () { self.last_update_date = last_update_date; }
+}
\ No newline at end of file
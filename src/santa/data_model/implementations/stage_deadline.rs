@@ -0,0 +1,45 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::ILocalObject;
+use crate::santa::data_model::enums::RoomState;
+use crate::santa::data_model::traits::{IPoolRelated, IStageDeadline};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StageDeadline {
+    id : String,
+    pool_id : String,
+    room_state : RoomState,
+    deadline : DateTime<Utc>
+}
+
+impl ILocalObject for StageDeadline {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IPoolRelated for StageDeadline {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IStageDeadline for StageDeadline {
+    fn new(id : &str, pool_id : &str, room_state : RoomState, deadline : DateTime<Utc>) -> Self {
+        return StageDeadline {
+            id: String::from(id),
+            pool_id: String::from(pool_id),
+            room_state: room_state,
+            deadline: deadline
+        };
+    }
+
+    fn room_state(&self) -> RoomState { self.room_state.clone() }
+
+    fn deadline(&self) -> DateTime<Utc> { self.deadline }
+
+    fn set_room_state(&mut self, room_state : RoomState) -> () { self.room_state = room_state; }
+
+    fn set_deadline(&mut self, deadline : DateTime<Utc>) -> () { self.deadline = deadline; }
+}
\ No newline at end of file
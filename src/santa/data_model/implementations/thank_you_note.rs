@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::ILocalObject;
+use crate::santa::data_model::traits::{IPoolRelated, IRoomRelated, IThankYouNote};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ThankYouNote {
+    id : String,
+    room_id : String,
+    pool_id : String,
+    text_content : String,
+    photo_type : String,
+    photo : String,
+    creation_date : DateTime<Utc>
+}
+
+impl ILocalObject for ThankYouNote {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IRoomRelated for ThankYouNote {
+    fn room_id(&self) -> &str { self.room_id.as_str() }
+
+    fn set_room_id(&mut self, room_id : &str) -> () { self.room_id = String::from(room_id); }
+}
+
+impl IPoolRelated for ThankYouNote {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IThankYouNote for ThankYouNote {
+    fn new(id : &str, room_id : &str, pool_id : &str, text_content : &str, photo_type : &str, photo : &str, creation_date : DateTime<Utc>) -> Self {
+        return ThankYouNote {
+            id: String::from(id),
+            room_id: String::from(room_id),
+            pool_id: String::from(pool_id),
+            text_content: String::from(text_content),
+            photo_type: String::from(photo_type),
+            photo: String::from(photo),
+            creation_date: creation_date
+        };
+    }
+
+    fn text_content(&self) -> &str { self.text_content.as_str() }
+
+    fn photo_type(&self) -> &str { self.photo_type.as_str() }
+
+    fn photo(&self) -> &str { self.photo.as_str() }
+
+    fn creation_date(&self) -> DateTime<Utc> { self.creation_date }
+
+    fn set_text_content(&mut self, text_content : &str) -> () { self.text_content = String::from(text_content); }
+
+    fn set_photo_type(&mut self, photo_type : &str) -> () { self.photo_type = String::from(photo_type); }
+
+    fn set_photo(&mut self, photo : &str) -> () { self.photo = String::from(photo); }
+
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> () { self.creation_date = creation_date; }
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::core::data_model::traits::{IAccountRelated, ILocalObject};
+use crate::santa::data_model::traits::{IPoolRelated, IWaitlistEntry};
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WaitlistEntry {
+    id : String,
+    pool_id : String,
+    account_id : String,
+    wishlist : String,
+    creation_date : DateTime<Utc>
+}
+
+impl ILocalObject for WaitlistEntry {
+    fn id(&self) -> &str { self.id.as_str() }
+
+    fn set_id(&mut self, id : &str) -> () { self.id = String::from(id) }
+}
+
+impl IAccountRelated for WaitlistEntry {
+    fn account_id(&self) -> &str { self.account_id.as_str() }
+
+    fn set_account_id(&mut self, account_id : &str) -> () { self.account_id = String::from(account_id) }
+}
+
+impl IPoolRelated for WaitlistEntry {
+    fn pool_id(&self) -> &str { self.pool_id.as_str() }
+
+    fn set_pool_id(&mut self, pool_id : &str) -> () { self.pool_id = String::from(pool_id); }
+}
+
+impl IWaitlistEntry for WaitlistEntry {
+    fn new(id : &str, pool_id : &str, account_id : &str, wishlist : &str, creation_date : DateTime<Utc>) -> Self {
+        return WaitlistEntry {
+            id: String::from(id),
+            pool_id: String::from(pool_id),
+            account_id: String::from(account_id),
+            wishlist: String::from(wishlist),
+            creation_date: creation_date
+        };
+    }
+
+    fn wishlist(&self) -> &str { self.wishlist.as_str() }
+
+    fn creation_date(&self) -> DateTime<Utc> { self.creation_date }
+
+    fn set_wishlist(&mut self, wishlist : &str) -> () { self.wishlist = String::from(wishlist); }
+
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> () { self.creation_date = creation_date; }
+}
\ No newline at end of file
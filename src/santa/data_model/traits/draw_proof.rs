@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+
+use crate::santa::data_model::traits::IPoolRelated;
+
+pub trait IDrawProof : IPoolRelated {
+    fn new(id : &str, pool_id : &str, seed : &str, commitment : &str, members : &str, forbidden_pairs : &str, history_pairs : &str, pairs : &str, creation_date : DateTime<Utc>) -> Self;
+
+    fn seed(&self) -> &str;
+    fn commitment(&self) -> &str;
+    fn members(&self) -> &str;
+    fn forbidden_pairs(&self) -> &str;
+    fn history_pairs(&self) -> &str;
+    fn pairs(&self) -> &str;
+    fn creation_date(&self) -> DateTime<Utc>;
+
+    fn set_seed(&mut self, seed : &str) -> ();
+    fn set_commitment(&mut self, commitment : &str) -> ();
+    fn set_members(&mut self, members : &str) -> ();
+    fn set_forbidden_pairs(&mut self, forbidden_pairs : &str) -> ();
+    fn set_history_pairs(&mut self, history_pairs : &str) -> ();
+    fn set_pairs(&mut self, pairs : &str) -> ();
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> ();
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::{core::data_model::traits::IAccountRelated, santa::data_model::traits::IPoolRelated};
+
+pub trait IExclusion : IAccountRelated + IPoolRelated {
+    fn new(id : &str, account_id : &str, pool_id : &str, first_account_id : &str, second_account_id : &str) -> Self;
+
+    fn first_account_id(&self) -> &str;
+    fn second_account_id(&self) -> &str;
+
+    fn set_first_account_id(&mut self, first_account_id : &str) -> ();
+    fn set_second_account_id(&mut self, second_account_id : &str) -> ();
+}
\ No newline at end of file
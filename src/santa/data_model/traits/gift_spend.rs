@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+
+use crate::santa::data_model::traits::{IPoolRelated, IRoomRelated};
+
+pub trait IGiftSpend : IRoomRelated + IPoolRelated {
+    fn new(id : &str, room_id : &str, pool_id : &str, amount : u64, currency : &str, creation_date : DateTime<Utc>) -> Self;
+
+    fn amount(&self) -> u64;
+    fn currency(&self) -> &str;
+    fn creation_date(&self) -> DateTime<Utc>;
+
+    fn set_amount(&mut self, amount : u64) -> ();
+    fn set_currency(&mut self, currency : &str) -> ();
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> ();
+}
\ No newline at end of file
@@ -1,8 +1,10 @@
 use crate::core::data_model::traits::IAccountRelated;
-use crate::santa::data_model::traits::{IPoolRelated, IRoomRelated};
+use crate::santa::data_model::traits::IPoolRelated;
 
-pub trait IMember : IAccountRelated + IRoomRelated  + IPoolRelated {
-    fn new(id : &str, account_id : &str, room_id : &str, pool_id : &str, wishlist : &str) -> Self;
+pub trait IMember : IAccountRelated + IPoolRelated {
+    fn new(id : &str, account_id : &str, pool_id : &str, wishlist : &str, group_name : &str) -> Self;
     fn wishlist(&self) -> &str;
+    fn group_name(&self) -> &str;
     fn set_wishlist(&mut self, wishlist : &str) -> ();
+    fn set_group_name(&mut self, group_name : &str) -> ();
 }
\ No newline at end of file
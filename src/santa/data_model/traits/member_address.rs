@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+
+use crate::{core::data_model::traits::IAccountRelated, santa::data_model::traits::IPoolRelated};
+
+pub trait IMemberAddress : IAccountRelated + IPoolRelated {
+    fn new(id : &str, pool_id : &str, account_id : &str, encrypted_address : &str, last_update_date : DateTime<Utc>) -> Self;
+
+    fn encrypted_address(&self) -> &str;
+    fn last_update_date(&self) -> DateTime<Utc>;
+
+    fn set_encrypted_address(&mut self, encrypted_address : &str) -> ();
+    fn set_last_update_date(&mut self, last_update_date : DateTime<Utc>) -> ();
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::{core::data_model::traits::IAccountRelated, santa::data_model::traits::IPoolRelated};
+
+pub trait IMemberAnswer : IAccountRelated + IPoolRelated {
+    fn new(id : &str, pool_id : &str, account_id : &str, question_id : &str, text_content : &str) -> Self;
+
+    fn question_id(&self) -> &str;
+    fn text_content(&self) -> &str;
+
+    fn set_question_id(&mut self, question_id : &str) -> ();
+    fn set_text_content(&mut self, text_content : &str) -> ();
+}
\ No newline at end of file
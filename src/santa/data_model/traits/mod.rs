@@ -4,10 +4,40 @@ mod pool;
 mod room;
 mod message;
 mod member;
+mod exclusion;
+mod draw_proof;
+mod room_state_change;
+mod shipment;
+mod member_address;
+mod thank_you_note;
+mod stage_deadline;
+mod pool_join_token;
+mod waitlist_entry;
+mod pool_invitation;
+mod pool_organizer;
+mod gift_spend;
+mod pool_announcement;
+mod pool_question;
+mod member_answer;
 
 pub use room_related::IRoomRelated;
 pub use pool_related::IPoolRelated;
 pub use pool::IPool;
 pub use room::IRoom;
 pub use message::IMessage;
-pub use member::IMember;
\ No newline at end of file
+pub use member::IMember;
+pub use exclusion::IExclusion;
+pub use draw_proof::IDrawProof;
+pub use room_state_change::IRoomStateChange;
+pub use shipment::IShipment;
+pub use member_address::IMemberAddress;
+pub use thank_you_note::IThankYouNote;
+pub use stage_deadline::IStageDeadline;
+pub use pool_join_token::IPoolJoinToken;
+pub use waitlist_entry::IWaitlistEntry;
+pub use pool_invitation::IPoolInvitation;
+pub use pool_organizer::IPoolOrganizer;
+pub use gift_spend::IGiftSpend;
+pub use pool_announcement::IPoolAnnouncement;
+pub use pool_question::IPoolQuestion;
+pub use member_answer::IMemberAnswer;
\ No newline at end of file
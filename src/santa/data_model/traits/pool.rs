@@ -1,10 +1,10 @@
 use chrono::{DateTime, Utc};
 
-use crate::{core::data_model::traits::IAccountRelated, santa::data_model::enums::PoolState};
+use crate::{core::data_model::traits::IAccountRelated, santa::data_model::enums::{PoolState, PoolVisibility, RoomState}};
 
 pub trait IPool : IAccountRelated {
 
-    fn new(id : &str, name : &str, description : &str, creator_id : &str, min_price : u64, max_price : u64, creation_date : DateTime<Utc>, lifetime : u64, state : PoolState) -> Self;
+    fn new(id : &str, name : &str, description : &str, creator_id : &str, min_price : u64, max_price : u64, creation_date : DateTime<Utc>, lifetime : u64, state : PoolState, previous_pool_id : &str, history_depth : u64, gifts_per_member : u64, cross_group_draw : bool, end_room_state : RoomState, open_at : Option<DateTime<Utc>>, draw_at : Option<DateTime<Utc>>, end_at : Option<DateTime<Utc>>, visibility : PoolVisibility, max_members : u64, currency : &str, archive_date : Option<DateTime<Utc>>, state_change_date : DateTime<Utc>) -> Self;
 
     fn name(&self) -> &str;
     fn description(&self) -> &str;
@@ -13,6 +13,19 @@ pub trait IPool : IAccountRelated {
     fn creation_date(&self) -> DateTime<Utc>;
     fn lifetime(&self) -> u64;
     fn state(&self) -> PoolState;
+    fn previous_pool_id(&self) -> &str;
+    fn history_depth(&self) -> u64;
+    fn gifts_per_member(&self) -> u64;
+    fn cross_group_draw(&self) -> bool;
+    fn end_room_state(&self) -> RoomState;
+    fn open_at(&self) -> Option<DateTime<Utc>>;
+    fn draw_at(&self) -> Option<DateTime<Utc>>;
+    fn end_at(&self) -> Option<DateTime<Utc>>;
+    fn visibility(&self) -> PoolVisibility;
+    fn max_members(&self) -> u64;
+    fn currency(&self) -> &str;
+    fn archive_date(&self) -> Option<DateTime<Utc>>;
+    fn state_change_date(&self) -> DateTime<Utc>;
 
     fn set_name(&mut self, name : &str) -> ();
     fn set_description(&mut self, description : &str) -> ();
@@ -21,4 +34,17 @@ pub trait IPool : IAccountRelated {
     fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> ();
     fn set_lifetime(&mut self, lifetime : u64) -> ();
     fn set_state(&mut self, state : PoolState) -> ();
+    fn set_previous_pool_id(&mut self, previous_pool_id : &str) -> ();
+    fn set_history_depth(&mut self, history_depth : u64) -> ();
+    fn set_gifts_per_member(&mut self, gifts_per_member : u64) -> ();
+    fn set_cross_group_draw(&mut self, cross_group_draw : bool) -> ();
+    fn set_end_room_state(&mut self, end_room_state : RoomState) -> ();
+    fn set_open_at(&mut self, open_at : Option<DateTime<Utc>>) -> ();
+    fn set_draw_at(&mut self, draw_at : Option<DateTime<Utc>>) -> ();
+    fn set_end_at(&mut self, end_at : Option<DateTime<Utc>>) -> ();
+    fn set_visibility(&mut self, visibility : PoolVisibility) -> ();
+    fn set_max_members(&mut self, max_members : u64) -> ();
+    fn set_currency(&mut self, currency : &str) -> ();
+    fn set_archive_date(&mut self, archive_date : Option<DateTime<Utc>>) -> ();
+    fn set_state_change_date(&mut self, state_change_date : DateTime<Utc>) -> ();
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+
+use crate::core::data_model::traits::IAccountRelated;
+use crate::santa::data_model::traits::IPoolRelated;
+
+pub trait IPoolAnnouncement : IAccountRelated + IPoolRelated {
+    fn new(id : &str, pool_id : &str, account_id : &str, text_content : &str, is_pinned : bool, creation_date : DateTime<Utc>, last_update_date : DateTime<Utc>) -> Self;
+
+    fn text_content(&self) -> &str;
+    fn is_pinned(&self) -> bool;
+    fn creation_date(&self) -> DateTime<Utc>;
+    fn last_update_date(&self) -> DateTime<Utc>;
+
+    fn set_text_content(&mut self, text_content : &str) -> ();
+    fn set_is_pinned(&mut self, is_pinned : bool) -> ();
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> ();
+    fn set_last_update_date(&mut self, last_update_date : DateTime<Utc>) -> ();
+}
\ No newline at end of file
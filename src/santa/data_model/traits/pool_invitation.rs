@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+
+use crate::{core::data_model::traits::IAccountRelated, santa::data_model::traits::IPoolRelated};
+
+pub trait IPoolInvitation : IAccountRelated + IPoolRelated {
+    fn new(id : &str, pool_id : &str, account_id : &str, creation_date : DateTime<Utc>) -> Self;
+
+    fn creation_date(&self) -> DateTime<Utc>;
+
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> ();
+}
\ No newline at end of file
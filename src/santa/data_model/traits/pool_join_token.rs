@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+
+use crate::santa::data_model::traits::IPoolRelated;
+
+pub trait IPoolJoinToken : IPoolRelated {
+    fn new(id : &str, pool_id : &str, token : &str, max_uses : u64, uses_count : u64, is_revoked : bool, creation_date : DateTime<Utc>) -> Self;
+
+    fn token(&self) -> &str;
+    fn max_uses(&self) -> u64;
+    fn uses_count(&self) -> u64;
+    fn is_revoked(&self) -> bool;
+    fn creation_date(&self) -> DateTime<Utc>;
+
+    fn set_token(&mut self, token : &str) -> ();
+    fn set_max_uses(&mut self, max_uses : u64) -> ();
+    fn set_uses_count(&mut self, uses_count : u64) -> ();
+    fn set_is_revoked(&mut self, is_revoked : bool) -> ();
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> ();
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+use crate::santa::data_model::traits::IPoolRelated;
+
+pub trait IPoolQuestion : IPoolRelated {
+    fn new(id : &str, pool_id : &str, text_content : &str, is_required : bool, position : u64) -> Self;
+
+    fn text_content(&self) -> &str;
+    fn is_required(&self) -> bool;
+    fn position(&self) -> u64;
+
+    fn set_text_content(&mut self, text_content : &str) -> ();
+    fn set_is_required(&mut self, is_required : bool) -> ();
+    fn set_position(&mut self, position : u64) -> ();
+}
\ No newline at end of file
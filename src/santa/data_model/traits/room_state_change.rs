@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+
+use crate::{core::data_model::traits::IAccountRelated, santa::data_model::{enums::RoomState, traits::{IPoolRelated, IRoomRelated}}};
+
+pub trait IRoomStateChange : IAccountRelated + IRoomRelated + IPoolRelated {
+    fn new(id : &str, room_id : &str, pool_id : &str, account_id : &str, room_state : RoomState, creation_date : DateTime<Utc>) -> Self;
+
+    fn room_state(&self) -> RoomState;
+    fn creation_date(&self) -> DateTime<Utc>;
+
+    fn set_room_state(&mut self, room_state : RoomState) -> ();
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> ();
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::santa::data_model::traits::{IPoolRelated, IRoomRelated};
+
+pub trait IShipment : IRoomRelated + IPoolRelated {
+    fn new(id : &str, room_id : &str, pool_id : &str, carrier_name : &str, tracking_number : &str, expected_delivery_date : Option<NaiveDate>, last_update_date : DateTime<Utc>) -> Self;
+
+    fn carrier_name(&self) -> &str;
+    fn tracking_number(&self) -> &str;
+    fn expected_delivery_date(&self) -> Option<NaiveDate>;
+    fn last_update_date(&self) -> DateTime<Utc>;
+
+    fn set_carrier_name(&mut self, carrier_name : &str) -> ();
+    fn set_tracking_number(&mut self, tracking_number : &str) -> ();
+    fn set_expected_delivery_date(&mut self, expected_delivery_date : Option<NaiveDate>) -> ();
+    fn set_last_update_date(&mut self, last_update_date : DateTime<Utc>) -> ();
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+
+use crate::santa::data_model::{enums::RoomState, traits::IPoolRelated};
+
+pub trait IStageDeadline : IPoolRelated {
+    fn new(id : &str, pool_id : &str, room_state : RoomState, deadline : DateTime<Utc>) -> Self;
+
+    fn room_state(&self) -> RoomState;
+    fn deadline(&self) -> DateTime<Utc>;
+
+    fn set_room_state(&mut self, room_state : RoomState) -> ();
+    fn set_deadline(&mut self, deadline : DateTime<Utc>) -> ();
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+
+use crate::santa::data_model::traits::{IPoolRelated, IRoomRelated};
+
+pub trait IThankYouNote : IRoomRelated + IPoolRelated {
+    fn new(id : &str, room_id : &str, pool_id : &str, text_content : &str, photo_type : &str, photo : &str, creation_date : DateTime<Utc>) -> Self;
+
+    fn text_content(&self) -> &str;
+    fn photo_type(&self) -> &str;
+    fn photo(&self) -> &str;
+    fn creation_date(&self) -> DateTime<Utc>;
+
+    fn set_text_content(&mut self, text_content : &str) -> ();
+    fn set_photo_type(&mut self, photo_type : &str) -> ();
+    fn set_photo(&mut self, photo : &str) -> ();
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> ();
+}
\ No newline at end of file
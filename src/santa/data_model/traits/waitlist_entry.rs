@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+
+use crate::{core::data_model::traits::IAccountRelated, santa::data_model::traits::IPoolRelated};
+
+pub trait IWaitlistEntry : IAccountRelated + IPoolRelated {
+    fn new(id : &str, pool_id : &str, account_id : &str, wishlist : &str, creation_date : DateTime<Utc>) -> Self;
+
+    fn wishlist(&self) -> &str;
+    fn creation_date(&self) -> DateTime<Utc>;
+
+    fn set_wishlist(&mut self, wishlist : &str) -> ();
+    fn set_creation_date(&mut self, creation_date : DateTime<Utc>) -> ();
+}
\ No newline at end of file
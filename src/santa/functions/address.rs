@@ -0,0 +1,109 @@
+use data_encoding::BASE64URL;
+use ring::{aead, hkdf, rand::{self, SecureRandom}};
+use serde::{Deserialize, Serialize};
+
+const ADDRESS_KEY_SALT : &[u8] = b"mini-santa delivery address";
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DeliveryAddress {
+    pub full_name : String,
+    pub country : String,
+    pub city : String,
+    pub address_line : String,
+    pub postal_code : String,
+    pub phone : String,
+    pub is_pickup_point : bool,
+    pub comment : String
+}
+
+// the key never leaves memory, it is derived from the server secret every time
+fn address_key(config_secret : &str) -> aead::LessSafeKey {
+    let salt = hkdf::Salt::new(hkdf::HKDF_SHA256, ADDRESS_KEY_SALT);
+    let prk = salt.extract(config_secret.as_bytes());
+    let okm = prk.expand(&[], &aead::AES_256_GCM).unwrap();
+    return aead::LessSafeKey::new(aead::UnboundKey::from(okm));
+}
+
+// the owner id is authenticated with the address, so a ciphertext copied to another member does not decrypt.
+// a nonce can not be reused with the same key, so nothing is encrypted when the system random fails
+pub fn encrypt_address(address : &DeliveryAddress, owner_id : &str, config_secret : &str) -> Option<String> {
+    let key = address_key(config_secret);
+    let rng = rand::SystemRandom::new();
+    let mut nonce_bytes = [0u8; aead::NONCE_LEN];
+    rng.fill(&mut nonce_bytes).ok()?;
+    let nonce = aead::Nonce::assume_unique_for_key(nonce_bytes);
+
+    let mut in_out = serde_json::to_vec(address).ok()?;
+    key.seal_in_place_append_tag(nonce, aead::Aad::from(owner_id.as_bytes()), &mut in_out).ok()?;
+
+    let mut result = nonce_bytes.to_vec();
+    result.extend(in_out);
+    return Some(BASE64URL.encode(&result));
+}
+
+pub fn decrypt_address(encrypted_address : &str, owner_id : &str, config_secret : &str) -> Option<DeliveryAddress> {
+    let bytes = BASE64URL.decode(encrypted_address.as_bytes()).ok()?;
+    if bytes.len() < aead::NONCE_LEN { return None; }
+    let (nonce_bytes, ciphertext) = bytes.split_at(aead::NONCE_LEN);
+    let nonce = aead::Nonce::try_assume_unique_for_key(nonce_bytes).ok()?;
+
+    let key = address_key(config_secret);
+    let mut in_out = ciphertext.to_vec();
+    let plain = key.open_in_place(nonce, aead::Aad::from(owner_id.as_bytes()), &mut in_out).ok()?;
+    return serde_json::from_slice(plain).ok();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_address() -> DeliveryAddress {
+        return DeliveryAddress {
+            full_name: String::from("Ivan Ivanov"),
+            country: String::from("RU"),
+            city: String::from("Moscow"),
+            address_line: String::from("Tverskaya st. 1, apt. 2"),
+            postal_code: String::from("125009"),
+            phone: String::from("+7 900 000-00-00"),
+            is_pickup_point: false,
+            comment: String::from("ring twice")
+        };
+    }
+
+    #[test]
+    fn address_round_trip() {
+        let encrypted = encrypt_address(&test_address(), "owner", "secret").unwrap();
+        let decrypted = decrypt_address(encrypted.as_str(), "owner", "secret").unwrap();
+        assert_eq!(serde_json::to_value(decrypted).unwrap(), serde_json::to_value(test_address()).unwrap());
+    }
+
+    #[test]
+    fn same_address_encrypts_with_different_nonces() {
+        let first = encrypt_address(&test_address(), "owner", "secret").unwrap();
+        let second = encrypt_address(&test_address(), "owner", "secret").unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn address_does_not_decrypt_for_another_owner() {
+        let encrypted = encrypt_address(&test_address(), "owner", "secret").unwrap();
+        assert!(decrypt_address(encrypted.as_str(), "other owner", "secret").is_none());
+    }
+
+    #[test]
+    fn address_does_not_decrypt_with_another_secret() {
+        let encrypted = encrypt_address(&test_address(), "owner", "secret").unwrap();
+        assert!(decrypt_address(encrypted.as_str(), "owner", "other secret").is_none());
+    }
+
+    #[test]
+    fn damaged_address_does_not_decrypt() {
+        let encrypted = encrypt_address(&test_address(), "owner", "secret").unwrap();
+        let mut bytes = BASE64URL.decode(encrypted.as_bytes()).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        assert!(decrypt_address(BASE64URL.encode(&bytes).as_str(), "owner", "secret").is_none());
+        assert!(decrypt_address("short", "owner", "secret").is_none());
+        assert!(decrypt_address("not base64 !", "owner", "secret").is_none());
+    }
+}
\ No newline at end of file
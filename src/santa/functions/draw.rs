@@ -0,0 +1,406 @@
+use std::collections::HashSet;
+
+use ::rand::{seq::SliceRandom, Rng, SeedableRng};
+use data_encoding::HEXLOWER;
+use rand_chacha::ChaCha20Rng;
+use ring::digest;
+
+// how many times a single random gift cycle is tried before falling back to the matching search
+const RANDOM_CYCLE_ATTEMPTS : usize = 64;
+
+pub fn is_pair_allowed(mailer : &str, recipient : &str, forbidden : &HashSet<[String; 2]>) -> bool {
+    if mailer == recipient { return false; }
+    let pair = [String::from(mailer), String::from(recipient)];
+    return !forbidden.contains(&pair);
+}
+
+fn try_make_cycle<R : Rng + ?Sized>(ids : &Vec<&str>, forbidden : &HashSet<[String; 2]>, rng : &mut R) -> Option<Vec<[String; 2]>> {
+    let mut mix = ids.clone();
+    mix.shuffle(rng);
+    mix.push(mix[0]);
+    let mut result : Vec<[String; 2]> = Vec::new();
+    let n = mix.len();
+    for i in 0..(n - 1) {
+        if !is_pair_allowed(mix[i], mix[i+1], forbidden) { return None; }
+        let temp : [String; 2] = [String::from(mix[i]), String::from(mix[i+1])];
+        result.push(temp);
+    }
+    return Some(result);
+}
+
+fn try_kuhn(mailer : usize, adjacency : &Vec<Vec<usize>>, visited : &mut Vec<bool>, recipient_to_mailer : &mut Vec<Option<usize>>) -> bool {
+    for &recipient in &adjacency[mailer] {
+        if visited[recipient] { continue; }
+        visited[recipient] = true;
+        let is_free = match recipient_to_mailer[recipient] {
+            None => true,
+            Some(other_mailer) => try_kuhn(other_mailer, adjacency, visited, recipient_to_mailer)
+        };
+        if is_free {
+            recipient_to_mailer[recipient] = Some(mailer);
+            return true;
+        }
+    }
+    return false;
+}
+
+// mailers reachable from an unmatched mailer by alternating paths,
+// they have fewer allowed recipients than there are of them (Hall's condition)
+fn find_blocked_mailers(start : usize, adjacency : &Vec<Vec<usize>>, recipient_to_mailer : &Vec<Option<usize>>) -> Vec<usize> {
+    let n = adjacency.len();
+    let mut visited_mailers = vec![false; n];
+    let mut visited_recipients = vec![false; n];
+    let mut queue = vec![start];
+    visited_mailers[start] = true;
+    while let Some(mailer) = queue.pop() {
+        for &recipient in &adjacency[mailer] {
+            if visited_recipients[recipient] { continue; }
+            visited_recipients[recipient] = true;
+            if let Some(other_mailer) = recipient_to_mailer[recipient] {
+                if !visited_mailers[other_mailer] {
+                    visited_mailers[other_mailer] = true;
+                    queue.push(other_mailer);
+                }
+            }
+        }
+    }
+    return (0..n).filter(|&i| {visited_mailers[i]}).collect();
+}
+
+fn try_make_matching<R : Rng + ?Sized>(ids : &Vec<&str>, forbidden : &HashSet<[String; 2]>, rng : &mut R) -> Result<Vec<[String; 2]>, Vec<String>> {
+    let n = ids.len();
+    let mut adjacency : Vec<Vec<usize>> = Vec::new();
+    for i in 0..n {
+        let mut allowed : Vec<usize> = (0..n).filter(|&j| {is_pair_allowed(ids[i], ids[j], forbidden)}).collect();
+        allowed.shuffle(rng);
+        adjacency.push(allowed);
+    }
+
+    let mut order : Vec<usize> = (0..n).collect();
+    order.shuffle(rng);
+
+    let mut recipient_to_mailer : Vec<Option<usize>> = vec![None; n];
+    for &mailer in &order {
+        let mut visited = vec![false; n];
+        if !try_kuhn(mailer, &adjacency, &mut visited, &mut recipient_to_mailer) {
+            let blocked = find_blocked_mailers(mailer, &adjacency, &recipient_to_mailer);
+            return Err(blocked.iter().map(|&i| {String::from(ids[i])}).collect());
+        }
+    }
+
+    let mut result : Vec<[String; 2]> = Vec::new();
+    for recipient in 0..n {
+        let mailer = recipient_to_mailer[recipient].unwrap();
+        result.push([String::from(ids[mailer]), String::from(ids[recipient])]);
+    }
+    result.shuffle(rng);
+    return Ok(result);
+}
+
+// everyone gives and receives one gift, nobody gifts themselves and no forbidden pair is used;
+// a single gift cycle is preferred, any valid derangement is accepted when the cycle does not fit.
+// on failure returns ids of mailers that have too few allowed recipients between them
+pub fn make_pairs<R : Rng + ?Sized>(ids : &Vec<&str>, forbidden : &HashSet<[String; 2]>, rng : &mut R) -> Result<Vec<[String; 2]>, Vec<String>> {
+    if ids.is_empty() { return Ok(Vec::new()); }
+    if ids.len() == 1 {
+        let id = String::from(ids[0]);
+        return Ok(vec![[id.clone(), id]]);
+    }
+
+    for _ in 0..RANDOM_CYCLE_ATTEMPTS {
+        let cycle_opt = try_make_cycle(ids, forbidden, rng);
+        if cycle_opt.is_some() { return Ok(cycle_opt.unwrap()); }
+    }
+
+    return try_make_matching(ids, forbidden, rng);
+}
+
+fn try_make_shifted_cycles<R : Rng + ?Sized>(ids : &Vec<&str>, forbidden : &HashSet<[String; 2]>, gifts_per_member : usize, rng : &mut R) -> Option<Vec<[String; 2]>> {
+    let mut mix = ids.clone();
+    mix.shuffle(rng);
+    let n = mix.len();
+    let mut result : Vec<[String; 2]> = Vec::new();
+    for shift in 1..=gifts_per_member {
+        for i in 0..n {
+            let recipient = mix[(i + shift) % n];
+            if !is_pair_allowed(mix[i], recipient, forbidden) { return None; }
+            result.push([String::from(mix[i]), String::from(recipient)]);
+        }
+    }
+    return Some(result);
+}
+
+fn try_augment(node : usize, sink : usize, capacity : &mut Vec<Vec<usize>>, neighbours : &Vec<Vec<usize>>, visited : &mut Vec<bool>) -> bool {
+    if node == sink { return true; }
+    visited[node] = true;
+    for &next in &neighbours[node] {
+        if visited[next] || capacity[node][next] == 0 { continue; }
+        if try_augment(next, sink, capacity, neighbours, visited) {
+            capacity[node][next] -= 1;
+            capacity[next][node] += 1;
+            return true;
+        }
+    }
+    return false;
+}
+
+// a flow from the source through mailers and recipients to the sink: every mailer sends gifts_per_member gifts,
+// every recipient takes as many and a mailer -> recipient pair carries one. a full flow always splits
+// into gifts_per_member derangements, so when there is none no draw is possible at all.
+// on failure the mailers still reachable from the source have too few allowed recipients between them
+fn try_make_k_matching<R : Rng + ?Sized>(ids : &Vec<&str>, forbidden : &HashSet<[String; 2]>, gifts_per_member : usize, rng : &mut R) -> Result<Vec<[String; 2]>, Vec<String>> {
+    let n = ids.len();
+    let source = 2 * n;
+    let sink = 2 * n + 1;
+    let mut capacity = vec![vec![0usize; 2 * n + 2]; 2 * n + 2];
+    let mut neighbours : Vec<Vec<usize>> = vec![Vec::new(); 2 * n + 2];
+
+    let mut mailers : Vec<usize> = (0..n).collect();
+    mailers.shuffle(rng);
+    for &mailer in &mailers {
+        capacity[source][mailer] = gifts_per_member;
+        neighbours[source].push(mailer);
+        neighbours[mailer].push(source);
+
+        let mut recipients : Vec<usize> = (0..n).filter(|&j| {is_pair_allowed(ids[mailer], ids[j], forbidden)}).collect();
+        recipients.shuffle(rng);
+        for recipient in recipients {
+            capacity[mailer][n + recipient] = 1;
+            neighbours[mailer].push(n + recipient);
+            neighbours[n + recipient].push(mailer);
+        }
+    }
+    for recipient in 0..n {
+        capacity[n + recipient][sink] = gifts_per_member;
+        neighbours[n + recipient].push(sink);
+        neighbours[sink].push(n + recipient);
+    }
+
+    let mut visited = vec![false; 2 * n + 2];
+    while try_augment(source, sink, &mut capacity, &neighbours, &mut visited) {
+        visited = vec![false; 2 * n + 2];
+    }
+    if (0..n).any(|mailer| {capacity[source][mailer] > 0}) {
+        return Err((0..n).filter(|&i| {visited[i]}).map(|i| {String::from(ids[i])}).collect());
+    }
+
+    let mut result : Vec<[String; 2]> = Vec::new();
+    for mailer in 0..n {
+        for recipient in 0..n {
+            if is_pair_allowed(ids[mailer], ids[recipient], forbidden) && capacity[mailer][n + recipient] == 0 {
+                result.push([String::from(ids[mailer]), String::from(ids[recipient])]);
+            }
+        }
+    }
+    result.shuffle(rng);
+    return Ok(result);
+}
+
+// every member gives and receives gifts_per_member gifts: the result is made of that many derangements
+// without a repeated mailer -> recipient pair. with one gift it is exactly make_pairs
+pub fn make_k_pairs<R : Rng + ?Sized>(ids : &Vec<&str>, forbidden : &HashSet<[String; 2]>, gifts_per_member : usize, rng : &mut R) -> Result<Vec<[String; 2]>, Vec<String>> {
+    if gifts_per_member <= 1 || ids.len() < 2 { return make_pairs(ids, forbidden, rng); }
+
+    for _ in 0..RANDOM_CYCLE_ATTEMPTS {
+        let cycles_opt = try_make_shifted_cycles(ids, forbidden, gifts_per_member, rng);
+        if cycles_opt.is_some() { return Ok(cycles_opt.unwrap()); }
+    }
+
+    return try_make_k_matching(ids, forbidden, gifts_per_member, rng);
+}
+
+// largest set of mailer -> recipient pairs where every mailer and every recipient is used at most once
+pub fn match_mailers_to_recipients(mailers : &Vec<&str>, recipients : &Vec<&str>, forbidden : &HashSet<[String; 2]>) -> Vec<[String; 2]> {
+    let mut adjacency : Vec<Vec<usize>> = Vec::new();
+    for mailer in mailers {
+        let allowed : Vec<usize> = (0..recipients.len()).filter(|&j| {is_pair_allowed(mailer, recipients[j], forbidden)}).collect();
+        adjacency.push(allowed);
+    }
+
+    let mut recipient_to_mailer : Vec<Option<usize>> = vec![None; recipients.len()];
+    for mailer in 0..mailers.len() {
+        let mut visited = vec![false; recipients.len()];
+        try_kuhn(mailer, &adjacency, &mut visited, &mut recipient_to_mailer);
+    }
+
+    let mut result : Vec<[String; 2]> = Vec::new();
+    for recipient in 0..recipients.len() {
+        if let Some(mailer) = recipient_to_mailer[recipient] {
+            result.push([String::from(mailers[mailer]), String::from(recipients[recipient])]);
+        }
+    }
+    return result;
+}
+
+// the same seed always gives the same random stream, so a draw can be replayed later
+pub fn draw_rng_from_seed(seed : &str) -> ChaCha20Rng {
+    let seed_hash = digest::digest(&digest::SHA256, seed.as_bytes());
+    let mut seed_bytes = [0u8; 32];
+    seed_bytes.copy_from_slice(seed_hash.as_ref());
+    return ChaCha20Rng::from_seed(seed_bytes);
+}
+
+// previous rounds are soft constraints, the oldest ones are dropped until the draw becomes possible.
+// returns the pairs or the blocked mailers and how many of the most recent rounds were respected
+pub fn make_draw(ids : &Vec<&str>, forbidden : &HashSet<[String; 2]>, history : &Vec<HashSet<[String; 2]>>, gifts_per_member : usize, seed : &str) -> (Result<Vec<[String; 2]>, Vec<String>>, usize) {
+    let mut respected_rounds = history.len();
+    loop {
+        let mut round_forbidden = forbidden.clone();
+        for round in history.iter().take(respected_rounds) {
+            round_forbidden.extend(round.iter().cloned());
+        }
+        let pairs_result = make_k_pairs(ids, &round_forbidden, gifts_per_member, &mut draw_rng_from_seed(seed));
+        if pairs_result.is_ok() || respected_rounds == 0 { return (pairs_result, respected_rounds); }
+        respected_rounds -= 1;
+    }
+}
+
+// mailer:recipient pairs separated by commas, sorted so the same set always gives the same string
+pub fn pairs_to_string<'a>(pairs : impl IntoIterator<Item = &'a [String; 2]>) -> String {
+    let mut pair_strings : Vec<String> = pairs.into_iter().map(|p| {format!("{}:{}", p[0], p[1])}).collect();
+    pair_strings.sort();
+    return pair_strings.join(",");
+}
+
+pub fn pairs_from_string(pairs : &str) -> Vec<[String; 2]> {
+    return pairs.split(",").filter_map(|p| {p.split_once(":")}).map(|(mailer, recipient)| {[String::from(mailer), String::from(recipient)]}).collect();
+}
+
+// rounds are separated by semicolons, the most recent round first
+pub fn rounds_to_string(rounds : &Vec<HashSet<[String; 2]>>) -> String {
+    return rounds.iter().map(|r| {pairs_to_string(r)}).collect::<Vec<String>>().join(";");
+}
+
+pub fn rounds_from_string(rounds : &str) -> Vec<HashSet<[String; 2]>> {
+    if rounds.is_empty() { return Vec::new(); }
+    return rounds.split(";").map(|r| {pairs_from_string(r).into_iter().collect()}).collect();
+}
+
+// the constraints and the result are committed together with the seed,
+// so the draw can be checked later even if exclusions or previous pools have changed since
+pub fn draw_commitment_input(pool_id : &str, seed : &str, account_ids : &Vec<&str>, forbidden_pairs : &str, history_pairs : &str, pairs : &str) -> String {
+    let mut sorted_account_ids = account_ids.clone();
+    sorted_account_ids.sort();
+    let members = sorted_account_ids.join(",");
+    return format!("{pool_id}\n{seed}\n{members}\n{forbidden_pairs}\n{history_pairs}\n{pairs}");
+}
+
+// sha-256 of the commitment input in lowercase hex, anyone can recompute it once the seed is revealed
+pub fn make_draw_commitment(pool_id : &str, seed : &str, account_ids : &Vec<&str>, forbidden_pairs : &str, history_pairs : &str, pairs : &str) -> String {
+    let commitment_input = draw_commitment_input(pool_id, seed, account_ids, forbidden_pairs, history_pairs, pairs);
+    let commitment_hash = digest::digest(&digest::SHA256, commitment_input.as_bytes());
+    return HEXLOWER.encode(commitment_hash.as_ref());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn forbid(pairs : &[(&str, &str)]) -> HashSet<[String; 2]> {
+        return pairs.iter().map(|&(mailer, recipient)| {[String::from(mailer), String::from(recipient)]}).collect();
+    }
+
+    // every member gives and receives exactly gifts_per_member gifts, no pair is repeated or forbidden
+    fn assert_valid_draw(ids : &Vec<&str>, forbidden : &HashSet<[String; 2]>, gifts_per_member : usize, pairs : &Vec<[String; 2]>) {
+        assert_eq!(pairs.len(), ids.len() * gifts_per_member);
+        let unique_pairs : HashSet<&[String; 2]> = pairs.iter().collect();
+        assert_eq!(unique_pairs.len(), pairs.len());
+        for pair in pairs {
+            assert!(is_pair_allowed(&pair[0], &pair[1], forbidden), "pair {:?} is not allowed", pair);
+        }
+        for id in ids {
+            assert_eq!(pairs.iter().filter(|p| {p[0] == *id}).count(), gifts_per_member);
+            assert_eq!(pairs.iter().filter(|p| {p[1] == *id}).count(), gifts_per_member);
+        }
+    }
+
+    #[test]
+    fn make_pairs_gives_one_gift_each() {
+        let ids = vec!["a", "b", "c", "d", "e"];
+        let forbidden = forbid(&[("a", "b"), ("b", "a"), ("c", "d")]);
+        for seed in ["1", "2", "3"] {
+            let pairs = make_pairs(&ids, &forbidden, &mut draw_rng_from_seed(seed)).unwrap();
+            assert_valid_draw(&ids, &forbidden, 1, &pairs);
+        }
+    }
+
+    #[test]
+    fn make_pairs_falls_back_to_matching_when_no_single_cycle_fits() {
+        // only a <-> b and c <-> d are allowed, so the draw is two separate cycles
+        let ids = vec!["a", "b", "c", "d"];
+        let forbidden = forbid(&[("a", "c"), ("a", "d"), ("b", "c"), ("b", "d"), ("c", "a"), ("c", "b"), ("d", "a"), ("d", "b")]);
+        let pairs = make_pairs(&ids, &forbidden, &mut draw_rng_from_seed("seed")).unwrap();
+        assert_valid_draw(&ids, &forbidden, 1, &pairs);
+        assert_eq!(pairs_to_string(&pairs), "a:b,b:a,c:d,d:c");
+    }
+
+    #[test]
+    fn make_pairs_reports_mailers_without_allowed_recipients() {
+        let ids = vec!["a", "b", "c"];
+        let forbidden = forbid(&[("a", "b"), ("a", "c")]);
+        let blocked = make_pairs(&ids, &forbidden, &mut draw_rng_from_seed("seed")).unwrap_err();
+        assert_eq!(blocked, vec![String::from("a")]);
+    }
+
+    #[test]
+    fn make_pairs_reports_every_mailer_of_a_hall_violation() {
+        // a and b both may give only to c
+        let ids = vec!["a", "b", "c", "d"];
+        let forbidden = forbid(&[("a", "b"), ("a", "d"), ("b", "a"), ("b", "d")]);
+        let mut blocked = make_pairs(&ids, &forbidden, &mut draw_rng_from_seed("seed")).unwrap_err();
+        blocked.sort();
+        assert_eq!(blocked, vec![String::from("a"), String::from("b")]);
+    }
+
+    #[test]
+    fn make_pairs_with_same_seed_is_reproducible() {
+        let ids = vec!["a", "b", "c", "d", "e", "f"];
+        let forbidden = forbid(&[("a", "b")]);
+        let first = make_pairs(&ids, &forbidden, &mut draw_rng_from_seed("seed")).unwrap();
+        let second = make_pairs(&ids, &forbidden, &mut draw_rng_from_seed("seed")).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn make_k_pairs_gives_k_gifts_each() {
+        let ids = vec!["a", "b", "c", "d", "e", "f"];
+        let forbidden = forbid(&[("a", "b"), ("c", "d"), ("e", "f")]);
+        for seed in ["1", "2", "3"] {
+            let pairs = make_k_pairs(&ids, &forbidden, 3, &mut draw_rng_from_seed(seed)).unwrap();
+            assert_valid_draw(&ids, &forbidden, 3, &pairs);
+        }
+    }
+
+    #[test]
+    fn make_k_pairs_uses_every_other_member_when_k_is_n_minus_one() {
+        let ids = vec!["a", "b", "c", "d"];
+        let pairs = make_k_pairs(&ids, &HashSet::new(), 3, &mut draw_rng_from_seed("seed")).unwrap();
+        assert_valid_draw(&ids, &HashSet::new(), 3, &pairs);
+    }
+
+    #[test]
+    fn make_k_pairs_falls_back_to_flow_search_when_no_shifted_cycles_fit() {
+        // a <-> b and c <-> d are forbidden, the only draw gives to the other couple
+        let ids = vec!["a", "b", "c", "d"];
+        let forbidden = forbid(&[("a", "b"), ("b", "a"), ("c", "d"), ("d", "c")]);
+        let pairs = make_k_pairs(&ids, &forbidden, 2, &mut draw_rng_from_seed("seed")).unwrap();
+        assert_valid_draw(&ids, &forbidden, 2, &pairs);
+        assert_eq!(pairs_to_string(&pairs), "a:c,a:d,b:c,b:d,c:a,c:b,d:a,d:b");
+    }
+
+    #[test]
+    fn make_k_pairs_fails_for_everyone_when_k_is_greater_than_n_minus_one() {
+        let ids = vec!["a", "b", "c"];
+        let mut blocked = make_k_pairs(&ids, &HashSet::new(), 3, &mut draw_rng_from_seed("seed")).unwrap_err();
+        blocked.sort();
+        assert_eq!(blocked, vec![String::from("a"), String::from("b"), String::from("c")]);
+    }
+
+    #[test]
+    fn make_k_pairs_reports_mailers_with_too_few_allowed_recipients() {
+        let ids = vec!["a", "b", "c", "d"];
+        let forbidden = forbid(&[("a", "b"), ("a", "c")]);
+        let blocked = make_k_pairs(&ids, &forbidden, 2, &mut draw_rng_from_seed("seed")).unwrap_err();
+        assert!(blocked.contains(&String::from("a")));
+    }
+}
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::{core::functions::execute_script_template_wo_return, AppState};
+use crate::{core::{functions::{command_result_exists, execute_script_template_wo_return, render_query_template}, services::escape_string}, santa::data_model::enums::RoomState, AppState};
 
 async fn create_pool_table(state : &AppState) -> () {
     const CREATE_POOL_TABLE_TEMPLATE: &str = "database_scripts/tables/create_pool_table.sql";
@@ -24,9 +24,189 @@ async fn create_message_table(state : &AppState) -> () {
     execute_script_template_wo_return(CREATE_MESSAGE_TABLE_TEMPLATE, &context, &state).await;
 }
 
+async fn create_exclusion_table(state : &AppState) -> () {
+    const CREATE_EXCLUSION_TABLE_TEMPLATE: &str = "database_scripts/tables/create_exclusion_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_EXCLUSION_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_draw_proof_table(state : &AppState) -> () {
+    const CREATE_DRAW_PROOF_TABLE_TEMPLATE: &str = "database_scripts/tables/create_draw_proof_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_DRAW_PROOF_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_room_state_change_table(state : &AppState) -> () {
+    const CREATE_ROOM_STATE_CHANGE_TABLE_TEMPLATE: &str = "database_scripts/tables/create_room_state_change_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_ROOM_STATE_CHANGE_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_shipment_table(state : &AppState) -> () {
+    const CREATE_SHIPMENT_TABLE_TEMPLATE: &str = "database_scripts/tables/create_shipment_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_SHIPMENT_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_member_address_table(state : &AppState) -> () {
+    const CREATE_MEMBER_ADDRESS_TABLE_TEMPLATE: &str = "database_scripts/tables/create_member_address_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_MEMBER_ADDRESS_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_thank_you_note_table(state : &AppState) -> () {
+    const CREATE_THANK_YOU_NOTE_TABLE_TEMPLATE: &str = "database_scripts/tables/create_thank_you_note_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_THANK_YOU_NOTE_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_stage_deadline_table(state : &AppState) -> () {
+    const CREATE_STAGE_DEADLINE_TABLE_TEMPLATE: &str = "database_scripts/tables/create_stage_deadline_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_STAGE_DEADLINE_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_pool_join_token_table(state : &AppState) -> () {
+    const CREATE_POOL_JOIN_TOKEN_TABLE_TEMPLATE: &str = "database_scripts/tables/create_pool_join_token_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_POOL_JOIN_TOKEN_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_waitlist_entry_table(state : &AppState) -> () {
+    const CREATE_WAITLIST_ENTRY_TABLE_TEMPLATE: &str = "database_scripts/tables/create_waitlist_entry_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_WAITLIST_ENTRY_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_pool_invitation_table(state : &AppState) -> () {
+    const CREATE_POOL_INVITATION_TABLE_TEMPLATE: &str = "database_scripts/tables/create_pool_invitation_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_POOL_INVITATION_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_pool_organizer_table(state : &AppState) -> () {
+    const CREATE_POOL_ORGANIZER_TABLE_TEMPLATE: &str = "database_scripts/tables/create_pool_organizer_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_POOL_ORGANIZER_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_gift_spend_table(state : &AppState) -> () {
+    const CREATE_GIFT_SPEND_TABLE_TEMPLATE: &str = "database_scripts/tables/create_gift_spend_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_GIFT_SPEND_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_pool_announcement_table(state : &AppState) -> () {
+    const CREATE_POOL_ANNOUNCEMENT_TABLE_TEMPLATE: &str = "database_scripts/tables/create_pool_announcement_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_POOL_ANNOUNCEMENT_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_pool_question_table(state : &AppState) -> () {
+    const CREATE_POOL_QUESTION_TABLE_TEMPLATE: &str = "database_scripts/tables/create_pool_question_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_POOL_QUESTION_TABLE_TEMPLATE, &context, &state).await;
+}
+
+async fn create_member_answer_table(state : &AppState) -> () {
+    const CREATE_MEMBER_ANSWER_TABLE_TEMPLATE: &str = "database_scripts/tables/create_member_answer_table.sql";
+    let context = tera::Context::new();
+    execute_script_template_wo_return(CREATE_MEMBER_ANSWER_TABLE_TEMPLATE, &context, &state).await;
+}
+
+// CREATE TABLE IF NOT EXISTS keeps the schema of a database made by an older version,
+// so columns added later are brought in here with defaults for the existing rows
+async fn is_column_exists(table_name : &str, column_name : &str, state : &AppState) -> bool {
+    const EXISTS_COLUMN_TEMPLATE: &str = "database_scripts/migrations/exists_column.sql";
+    let mut context = tera::Context::new();
+    context.insert("table_name", &table_name);
+    context.insert("column_name", &column_name);
+
+    let command = render_query_template(EXISTS_COLUMN_TEMPLATE, &context, &state).await;
+    return command_result_exists(command.as_str(), state).await;
+}
+
+async fn add_column_if_not_exists(table_name : &str, column_name : &str, column_definition : &str, state : &AppState) -> () {
+    const ADD_COLUMN_TEMPLATE: &str = "database_scripts/migrations/add_column.sql";
+    if is_column_exists(table_name, column_name, state).await { return; }
+
+    let mut context = tera::Context::new();
+    context.insert("table_name", &table_name);
+    context.insert("column_name", &column_name);
+    context.insert("column_definition", &column_definition);
+    execute_script_template_wo_return(ADD_COLUMN_TEMPLATE, &context, &state).await;
+    tracing::info!("Column \"{column_name}\" was added to table \"{table_name}\"");
+}
+
+async fn drop_column_if_exists(table_name : &str, column_name : &str, state : &AppState) -> () {
+    const DROP_COLUMN_TEMPLATE: &str = "database_scripts/migrations/drop_column.sql";
+    if !is_column_exists(table_name, column_name, state).await { return; }
+
+    let mut context = tera::Context::new();
+    context.insert("table_name", &table_name);
+    context.insert("column_name", &column_name);
+    execute_script_template_wo_return(DROP_COLUMN_TEMPLATE, &context, &state).await;
+    tracing::info!("Column \"{column_name}\" was dropped from table \"{table_name}\"");
+}
+
+async fn migrate_pool_table(state : &AppState) -> () {
+    const BACKFILL_POOL_STATE_CHANGE_DATE_TEMPLATE: &str = "database_scripts/migrations/backfill_pool_state_change_date.sql";
+    let default_currency = state.config.lock().await.santa.default_currency.clone();
+    let end_room_state_definition = format!("INTEGER NOT NULL DEFAULT {}", RoomState::RecipientTookTheGift as usize);
+    let currency_definition = format!("VARCHAR(3) NOT NULL DEFAULT '{}'", escape_string(default_currency.as_str()));
+
+    add_column_if_not_exists("pools", "previous_pool_id", "VARCHAR(36) NOT NULL DEFAULT ''", state).await;
+    add_column_if_not_exists("pools", "history_depth", "INTEGER NOT NULL DEFAULT 0", state).await;
+    add_column_if_not_exists("pools", "gifts_per_member", "INTEGER NOT NULL DEFAULT 1", state).await;
+    add_column_if_not_exists("pools", "cross_group_draw", "BOOLEAN NOT NULL DEFAULT 'false'", state).await;
+    add_column_if_not_exists("pools", "end_room_state", end_room_state_definition.as_str(), state).await;
+    add_column_if_not_exists("pools", "open_at", "VARCHAR(64) NOT NULL DEFAULT ''", state).await;
+    add_column_if_not_exists("pools", "draw_at", "VARCHAR(64) NOT NULL DEFAULT ''", state).await;
+    add_column_if_not_exists("pools", "end_at", "VARCHAR(64) NOT NULL DEFAULT ''", state).await;
+    add_column_if_not_exists("pools", "visibility", "INTEGER NOT NULL DEFAULT 0", state).await;
+    add_column_if_not_exists("pools", "max_members", "INTEGER NOT NULL DEFAULT 0", state).await;
+    add_column_if_not_exists("pools", "currency", currency_definition.as_str(), state).await;
+    add_column_if_not_exists("pools", "archive_date", "VARCHAR(64) NOT NULL DEFAULT ''", state).await;
+    add_column_if_not_exists("pools", "state_change_date", "VARCHAR(64) NOT NULL DEFAULT ''", state).await;
+
+    // the moment of the last state change of an old pool is unknown, its creation is the best guess
+    let context = tera::Context::new();
+    execute_script_template_wo_return(BACKFILL_POOL_STATE_CHANGE_DATE_TEMPLATE, &context, &state).await;
+}
+
+async fn migrate_member_table(state : &AppState) -> () {
+    // members are linked to rooms through the rooms table now, the old NOT NULL column would reject every insert
+    drop_column_if_exists("members", "room_id", state).await;
+    add_column_if_not_exists("members", "group_name", "VARCHAR(256) NOT NULL DEFAULT ''", state).await;
+}
+
+async fn migrate_draw_proof_table(state : &AppState) -> () {
+    add_column_if_not_exists("draw_proofs", "forbidden_pairs", "TEXT NOT NULL DEFAULT ''", state).await;
+    add_column_if_not_exists("draw_proofs", "history_pairs", "TEXT NOT NULL DEFAULT ''", state).await;
+    add_column_if_not_exists("draw_proofs", "pairs", "TEXT NOT NULL DEFAULT ''", state).await;
+}
+
 pub async fn santa_init_database(state : &AppState) -> () {
     create_pool_table(state).await;
+    migrate_pool_table(state).await;
     create_room_table(state).await;
     create_member_table(state).await;
+    migrate_member_table(state).await;
     create_message_table(state).await;
+    create_exclusion_table(state).await;
+    create_draw_proof_table(state).await;
+    migrate_draw_proof_table(state).await;
+    create_room_state_change_table(state).await;
+    create_shipment_table(state).await;
+    create_member_address_table(state).await;
+    create_thank_you_note_table(state).await;
+    create_stage_deadline_table(state).await;
+    create_pool_join_token_table(state).await;
+    create_waitlist_entry_table(state).await;
+    create_pool_invitation_table(state).await;
+    create_pool_organizer_table(state).await;
+    create_gift_spend_table(state).await;
+    create_pool_announcement_table(state).await;
+    create_pool_question_table(state).await;
+    create_member_answer_table(state).await;
 }
\ No newline at end of file
@@ -1,3 +1,9 @@
 mod init_database;
+mod draw;
+mod address;
+mod price;
 
-pub use init_database::*;
\ No newline at end of file
+pub use init_database::*;
+pub use draw::*;
+pub use address::*;
+pub use price::*;
\ No newline at end of file
@@ -0,0 +1,55 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::services::{IDbService, SQLiteDbService}, santa::data_model::{implementations::DrawProof, traits::IDrawProof}, AppState};
+
+pub fn row_to_draw_proof(row : &SqliteRow) -> DrawProof {
+    let id : &str = row.get("id");
+    let pool_id : &str = row.get("pool_id");
+    let seed : &str = row.get("seed");
+    let commitment : &str = row.get("commitment");
+    let members : &str = row.get("members");
+    let forbidden_pairs : &str = row.get("forbidden_pairs");
+    let history_pairs : &str = row.get("history_pairs");
+    let pairs : &str = row.get("pairs");
+    let creation_date_str : &str = row.get("creation_date");
+    let creation_date : DateTime<Utc> = DateTime::from_str(creation_date_str).unwrap();
+    return DrawProof::new(id, pool_id, seed, commitment, members, forbidden_pairs, history_pairs, pairs, creation_date);
+}
+
+pub async fn get_draw_proof_by_pool_id(pool_id : &str, state : &AppState) -> Option<impl IDrawProof> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_one_by_prop("draw_proofs", "pool_id", pool_id, row_to_draw_proof).await;
+}
+
+pub async fn is_draw_proof_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("draw_proofs", "id", id).await;
+}
+
+pub async fn create_draw_proof(id : &str, pool_id : &str, seed : &str, commitment : &str, members : &str, forbidden_pairs : &str, history_pairs : &str, pairs : &str, creation_date : DateTime<Utc>, state : &AppState) -> () {
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("draw_proofs",
+    vec!["id", "pool_id", "seed", "commitment", "members", "forbidden_pairs", "history_pairs", "pairs", "creation_date"],
+    vec![vec![id, pool_id, seed, commitment, members, forbidden_pairs, history_pairs, pairs, creation_date_str]]).await;
+}
+
+pub fn create_draw_proof_query(id : &str, pool_id : &str, seed : &str, commitment : &str, members : &str, forbidden_pairs : &str, history_pairs : &str, pairs : &str, creation_date : DateTime<Utc>, state : &AppState) -> String {
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    return db_service.insert_query("draw_proofs",
+    vec!["id", "pool_id", "seed", "commitment", "members", "forbidden_pairs", "history_pairs", "pairs", "creation_date"],
+    vec![vec![id, pool_id, seed, commitment, members, forbidden_pairs, history_pairs, pairs, creation_date_str]]);
+}
+
+pub fn delete_draw_proofs_by_pool_id_query(pool_id : &str, state : &AppState) -> String {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.delete_query("draw_proofs", "pool_id", vec![pool_id]);
+}
\ No newline at end of file
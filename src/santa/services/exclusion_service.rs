@@ -0,0 +1,39 @@
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::services::{IDbService, SQLiteDbService}, santa::data_model::{implementations::Exclusion, traits::IExclusion}, AppState};
+
+pub fn row_to_exclusion(row : &SqliteRow) -> Exclusion {
+    let id : &str = row.get("id");
+    let account_id : &str = row.get("account_id");
+    let pool_id : &str = row.get("pool_id");
+    let first_account_id : &str = row.get("first_account_id");
+    let second_account_id : &str = row.get("second_account_id");
+    return Exclusion::new(id, account_id, pool_id, first_account_id, second_account_id);
+}
+
+pub async fn get_exclusion_by_id(id : &str, state : &AppState) -> Option<impl IExclusion> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_one_by_prop("exclusions", "id", id, row_to_exclusion).await;
+}
+
+pub async fn get_exclusions_by_pool_id(pool_id : &str, state : &AppState) -> Option<Vec<impl IExclusion>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("exclusions", "pool_id", vec![pool_id], row_to_exclusion).await;
+}
+
+pub async fn is_exclusion_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("exclusions", "id", id).await;
+}
+
+pub async fn create_exclusion(id : &str, account_id : &str, pool_id : &str, first_account_id : &str, second_account_id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("exclusions",
+    vec!["id", "account_id", "pool_id", "first_account_id", "second_account_id"],
+    vec![vec![id, account_id, pool_id, first_account_id, second_account_id]]).await;
+}
+
+pub async fn delete_exclusion_by_id(id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    db_service.delete_one_by_prop("exclusions", "id", id).await;
+}
\ No newline at end of file
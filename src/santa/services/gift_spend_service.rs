@@ -0,0 +1,54 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::services::{IDbService, SQLiteDbService}, santa::data_model::{implementations::GiftSpend, traits::IGiftSpend}, AppState};
+
+pub fn row_to_gift_spend(row : &SqliteRow) -> GiftSpend {
+    let id : &str = row.get("id");
+    let room_id : &str = row.get("room_id");
+    let pool_id : &str = row.get("pool_id");
+    let amount : u64 = row.get("amount");
+    let currency : &str = row.get("currency");
+    let creation_date_str : &str = row.get("creation_date");
+    let creation_date : DateTime<Utc> = DateTime::from_str(creation_date_str).unwrap();
+    return GiftSpend::new(id, room_id, pool_id, amount, currency, creation_date);
+}
+
+pub async fn get_gift_spend_by_room_id(room_id : &str, state : &AppState) -> Option<impl IGiftSpend> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_one_by_prop("gift_spends", "room_id", room_id, row_to_gift_spend).await;
+}
+
+pub async fn get_gift_spends_by_pool_id(pool_id : &str, state : &AppState) -> Option<Vec<impl IGiftSpend>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("gift_spends", "pool_id", vec![pool_id], row_to_gift_spend).await;
+}
+
+pub async fn is_gift_spend_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("gift_spends", "id", id).await;
+}
+
+pub async fn create_gift_spend(id : &str, room_id : &str, pool_id : &str, amount : u64, currency : &str, creation_date : DateTime<Utc>, state : &AppState) -> () {
+    let amount_string = amount.to_string();
+    let amount_str = amount_string.as_str();
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("gift_spends",
+    vec!["id", "room_id", "pool_id", "amount", "currency", "creation_date"],
+    vec![vec![id, room_id, pool_id, amount_str, currency, creation_date_str]]).await;
+}
+
+pub async fn set_gift_spend_by_id(id : &str, amount : u64, currency : &str, creation_date : DateTime<Utc>, state : &AppState) -> () {
+    let amount_string = amount.to_string();
+    let amount_str = amount_string.as_str();
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("gift_spends", "id", id, vec!["amount", "currency", "creation_date"], vec![amount_str, currency, creation_date_str]).await;
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::{functions::{get_one_item_from_command, render_query_template}, services::{IDbService, SQLiteDbService}}, santa::data_model::{implementations::MemberAddress, traits::IMemberAddress}, AppState};
+
+pub fn row_to_member_address(row : &SqliteRow) -> MemberAddress {
+    let id : &str = row.get("id");
+    let pool_id : &str = row.get("pool_id");
+    let account_id : &str = row.get("account_id");
+    let encrypted_address : &str = row.get("encrypted_address");
+    let last_update_date_str : &str = row.get("last_update_date");
+    let last_update_date : DateTime<Utc> = DateTime::from_str(last_update_date_str).unwrap();
+    return MemberAddress::new(id, pool_id, account_id, encrypted_address, last_update_date);
+}
+
+pub async fn get_member_address_by_pool_and_account_ids(pool_id : &str, account_id : &str, state : &AppState) -> Option<impl IMemberAddress> {
+    const GET_MEMBER_ADDRESS_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE : &str = "database_scripts/member_address/get_member_address_by_pool_and_account_ids.sql";
+    let mut context = tera::Context::new();
+    context.insert("pool_id", &pool_id);
+    context.insert("account_id", &account_id);
+
+    let command = render_query_template(GET_MEMBER_ADDRESS_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE, &context, &state).await;
+    return get_one_item_from_command(command.as_str(), state, row_to_member_address).await;
+}
+
+pub async fn is_member_address_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("member_addresses", "id", id).await;
+}
+
+pub async fn create_member_address(id : &str, pool_id : &str, account_id : &str, encrypted_address : &str, last_update_date : DateTime<Utc>, state : &AppState) -> () {
+    let last_update_date_string = last_update_date.to_rfc3339();
+    let last_update_date_str = last_update_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("member_addresses",
+    vec!["id", "pool_id", "account_id", "encrypted_address", "last_update_date"],
+    vec![vec![id, pool_id, account_id, encrypted_address, last_update_date_str]]).await;
+}
+
+pub async fn set_member_address_by_id(id : &str, encrypted_address : &str, last_update_date : DateTime<Utc>, state : &AppState) -> () {
+    let last_update_date_string = last_update_date.to_rfc3339();
+    let last_update_date_str = last_update_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("member_addresses", "id", id, vec!["encrypted_address", "last_update_date"], vec![encrypted_address, last_update_date_str]).await;
+}
+
+pub async fn delete_member_address_by_id(id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    db_service.delete_one_by_prop("member_addresses", "id", id).await;
+}
+
+pub async fn delete_member_addresses_by_pool_id(pool_id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    db_service.delete_many_by_prop("member_addresses", "pool_id", vec![pool_id]).await;
+}
\ No newline at end of file
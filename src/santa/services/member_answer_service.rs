@@ -0,0 +1,43 @@
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::{functions::{execute_script_template_wo_return, get_many_items_from_command, render_query_template}, services::{IDbService, SQLiteDbService}}, santa::data_model::{implementations::MemberAnswer, traits::IMemberAnswer}, AppState};
+
+pub fn row_to_member_answer(row : &SqliteRow) -> MemberAnswer {
+    let id : &str = row.get("id");
+    let pool_id : &str = row.get("pool_id");
+    let account_id : &str = row.get("account_id");
+    let question_id : &str = row.get("question_id");
+    let text_content : &str = row.get("text_content");
+    return MemberAnswer::new(id, pool_id, account_id, question_id, text_content);
+}
+
+pub async fn get_member_answers_by_pool_and_account_ids(pool_id : &str, account_id : &str, state : &AppState) -> Option<Vec<impl IMemberAnswer>> {
+    const GET_MEMBER_ANSWERS_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE : &str = "database_scripts/member_answer/get_member_answers_by_pool_and_account_ids.sql";
+    let mut context = tera::Context::new();
+    context.insert("pool_id", &pool_id);
+    context.insert("account_id", &account_id);
+
+    let command = render_query_template(GET_MEMBER_ANSWERS_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE, &context, &state).await;
+    return get_many_items_from_command(command.as_str(), state, row_to_member_answer).await;
+}
+
+pub async fn is_member_answer_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("member_answers", "id", id).await;
+}
+
+pub async fn create_member_answer(id : &str, pool_id : &str, account_id : &str, question_id : &str, text_content : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("member_answers",
+    vec!["id", "pool_id", "account_id", "question_id", "text_content"],
+    vec![vec![id, pool_id, account_id, question_id, text_content]]).await;
+}
+
+pub async fn delete_member_answers_by_pool_and_account_ids(pool_id : &str, account_id : &str, state : &AppState) -> () {
+    const DELETE_MEMBER_ANSWERS_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE : &str = "database_scripts/member_answer/delete_member_answers_by_pool_and_account_ids.sql";
+    let mut context = tera::Context::new();
+    context.insert("pool_id", &pool_id);
+    context.insert("account_id", &account_id);
+
+    execute_script_template_wo_return(DELETE_MEMBER_ANSWERS_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE, &context, &state).await;
+}
\ No newline at end of file
@@ -1,14 +1,14 @@
 use sqlx::{sqlite::SqliteRow, Row};
 
-use crate::{core::{functions::{command_result_exists, get_one_item_from_command, render_query_template}, services::{IDbService, SQLiteDbService}}, santa::data_model::{implementations::Member, traits::IMember}, AppState};
+use crate::{core::{functions::{command_result_exists, get_many_rows_from_command, get_one_item_from_command, render_query_template}, services::{IDbService, SQLiteDbService}}, santa::data_model::{implementations::Member, traits::IMember}, AppState};
 
 pub fn row_to_member(row : &SqliteRow) -> Member {
     let id : &str = row.get("id");
     let account_id : &str = row.get("account_id");
-    let room_id : &str = row.get("room_id");
     let pool_id : &str = row.get("pool_id");
     let wishlist : &str = row.get("wishlist");
-    return Member::new(id, account_id, room_id, pool_id, wishlist);
+    let group_name : &str = row.get("group_name");
+    return Member::new(id, account_id, pool_id, wishlist, group_name);
 }
 
 pub async fn get_member_by_id(id : &str, state : &AppState) -> Option<impl IMember> {
@@ -21,11 +21,6 @@ pub async fn get_members_by_account_id(account_id : &str, state : &AppState) ->
     return db_service.get_many_by_prop("members", "account_id", vec![account_id], row_to_member).await;
 }
 
-pub async fn get_members_by_room_id(room_id : &str, state : &AppState) -> Option<Vec<impl IMember>> {
-    let db_service = SQLiteDbService::new(state);
-    return db_service.get_many_by_prop("members", "room_id", vec![room_id], row_to_member).await;
-}
-
 pub async fn get_members_by_pool_id(pool_id : &str, state : &AppState) -> Option<Vec<impl IMember>> {
     let db_service = SQLiteDbService::new(state);
     return db_service.get_many_by_prop("members", "pool_id", vec![pool_id], row_to_member).await;
@@ -41,6 +36,21 @@ pub async fn get_member_by_pool_and_account_ids(pool_id : &str, account_id : &st
     return get_one_item_from_command(command.as_str(), state, row_to_member).await;
 }
 
+// returns the member count and the count of members with an empty wishlist
+pub async fn count_members_by_pool_id(pool_id : &str, state : &AppState) -> (u64, u64) {
+    const COUNT_MEMBERS_BY_POOL_ID_TEMPLATE : &str = "database_scripts/member/count_members_by_pool_id.sql";
+    let mut context = tera::Context::new();
+    context.insert("pool_id", &pool_id);
+
+    let command = render_query_template(COUNT_MEMBERS_BY_POOL_ID_TEMPLATE, &context, &state).await;
+    let rows = get_many_rows_from_command(command.as_str(), state, |row| {
+        let member_count : u64 = row.get("member_count");
+        let empty_wishlist_count : u64 = row.get("empty_wishlist_count");
+        (member_count, empty_wishlist_count)
+    }).await.unwrap_or(vec![]);
+    return rows.first().cloned().unwrap_or((0, 0));
+}
+
 pub async fn is_member_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
     let db_service = SQLiteDbService::new(state);
     return db_service.exists_by_prop("members", "id", id).await;
@@ -55,9 +65,9 @@ pub async fn is_member_already_exists_by_pool_and_account_ids(pool_id : &str, ac
     return command_result_exists(command.as_str(), state).await;
 }
 
-pub async fn create_member(id : &str, account_id : &str, room_id : &str, pool_id : &str, wishlist : &str, state : &AppState) -> () {
+pub async fn create_member(id : &str, account_id : &str, pool_id : &str, wishlist : &str, group_name : &str, state : &AppState) -> () {
     let db_service = SQLiteDbService::new(state);
-    let _ = db_service.insert("members", vec!["id", "account_id", "room_id", "pool_id", "wishlist"], vec![vec![id, account_id, room_id, pool_id, wishlist]]).await;
+    let _ = db_service.insert("members", vec!["id", "account_id", "pool_id", "wishlist", "group_name"], vec![vec![id, account_id, pool_id, wishlist, group_name]]).await;
 }
 
 pub async fn set_wishlist_by_id(id : &str, wishlist : &str, state : &AppState) -> () {
@@ -65,9 +75,14 @@ pub async fn set_wishlist_by_id(id : &str, wishlist : &str, state : &AppState) -
     db_service.update("members", "id", id, vec!["wishlist"], vec![wishlist]).await;
 }
 
-pub async fn set_member_room_id(id : &str, room_id : &str, state : &AppState) -> () {
+pub async fn set_group_name_by_id(id : &str, group_name : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("members", "id", id, vec!["group_name"], vec![group_name]).await;
+}
+
+pub fn delete_member_by_id_query(id : &str, state : &AppState) -> String {
     let db_service = SQLiteDbService::new(state);
-    db_service.update("members", "id", id, vec!["room_id"], vec![room_id]).await;
+    return db_service.delete_query("members", "id", vec![id]);
 }
 
 pub async fn delete_member_by_id(id : &str, state : &AppState) -> () {
@@ -3,7 +3,7 @@ use std::str::FromStr;
 use chrono::{DateTime, Utc};
 use sqlx::{sqlite::SqliteRow, Executor, Row};
 
-use crate::{core::{functions::execute_script_template_wo_return, services::{escape_string, IDbService, SQLiteDbService}}, santa::data_model::{implementations::Message, traits::IMessage}, AppState};
+use crate::{core::{functions::{execute_script_template_wo_return, get_many_rows_from_command, render_query_template}, services::{escape_string, IDbService, SQLiteDbService}}, santa::data_model::{implementations::Message, traits::IMessage}, AppState};
 
 pub fn row_to_message(row : &SqliteRow) -> Message {
     let id : &str = row.get("id");
@@ -80,6 +80,20 @@ pub async fn get_last_messages_by_room_id(room_id : &str, limit : usize, state :
     return Some(objs);
 }
 
+// days are formatted as YYYY-MM-DD in UTC
+pub async fn count_messages_by_pool_id_per_day(pool_id : &str, state : &AppState) -> Option<Vec<(String, u64)>> {
+    const COUNT_MESSAGES_BY_POOL_ID_PER_DAY_TEMPLATE : &str = "database_scripts/message/count_messages_by_pool_id_per_day.sql";
+    let mut context = tera::Context::new();
+    context.insert("pool_id", &pool_id);
+
+    let command = render_query_template(COUNT_MESSAGES_BY_POOL_ID_PER_DAY_TEMPLATE, &context, &state).await;
+    return get_many_rows_from_command(command.as_str(), state, |row| {
+        let day : String = row.get("day");
+        let message_count : u64 = row.get("message_count");
+        (day, message_count)
+    }).await;
+}
+
 pub async fn delete_messages_if_limit_or_lifetime(state : &AppState) -> () {
     const DELETE_OLD_MESSAGES_TEMPLATE : &str = "database_scripts/message/delete_old_messages.sql";
     let now_time = Utc::now();
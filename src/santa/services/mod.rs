@@ -3,9 +3,39 @@ mod room_service;
 mod member_service;
 mod message_service;
 mod santa_service;
+mod exclusion_service;
+mod draw_proof_service;
+mod room_state_change_service;
+mod shipment_service;
+mod member_address_service;
+mod thank_you_note_service;
+mod stage_deadline_service;
+mod pool_join_token_service;
+mod waitlist_entry_service;
+mod pool_invitation_service;
+mod pool_organizer_service;
+mod gift_spend_service;
+mod pool_announcement_service;
+mod pool_question_service;
+mod member_answer_service;
 
 pub use pool_service::*;
 pub use room_service::*;
 pub use member_service::*;
 pub use message_service::*;
-pub use santa_service::*;
\ No newline at end of file
+pub use santa_service::*;
+pub use exclusion_service::*;
+pub use draw_proof_service::*;
+pub use room_state_change_service::*;
+pub use shipment_service::*;
+pub use member_address_service::*;
+pub use thank_you_note_service::*;
+pub use stage_deadline_service::*;
+pub use pool_join_token_service::*;
+pub use waitlist_entry_service::*;
+pub use pool_invitation_service::*;
+pub use pool_organizer_service::*;
+pub use gift_spend_service::*;
+pub use pool_announcement_service::*;
+pub use pool_question_service::*;
+pub use member_answer_service::*;
\ No newline at end of file
@@ -0,0 +1,68 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::services::{IDbService, SQLiteDbService}, santa::data_model::{implementations::PoolAnnouncement, traits::IPoolAnnouncement}, AppState};
+
+pub fn row_to_pool_announcement(row : &SqliteRow) -> PoolAnnouncement {
+    let id : &str = row.get("id");
+    let pool_id : &str = row.get("pool_id");
+    let account_id : &str = row.get("account_id");
+    let text_content : &str = row.get("text_content");
+    let is_pinned_str : &str = row.get("is_pinned");
+    let is_pinned = is_pinned_str.to_lowercase() == "true";
+    let creation_date_str : &str = row.get("creation_date");
+    let creation_date : DateTime<Utc> = DateTime::from_str(creation_date_str).unwrap();
+    let last_update_date_str : &str = row.get("last_update_date");
+    let last_update_date : DateTime<Utc> = DateTime::from_str(last_update_date_str).unwrap();
+    return PoolAnnouncement::new(id, pool_id, account_id, text_content, is_pinned, creation_date, last_update_date);
+}
+
+pub async fn get_pool_announcement_by_id(id : &str, state : &AppState) -> Option<impl IPoolAnnouncement> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_one_by_prop("pool_announcements", "id", id, row_to_pool_announcement).await;
+}
+
+pub async fn get_pool_announcements_by_pool_id(pool_id : &str, state : &AppState) -> Option<Vec<impl IPoolAnnouncement>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("pool_announcements", "pool_id", vec![pool_id], row_to_pool_announcement).await;
+}
+
+pub async fn is_pool_announcement_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("pool_announcements", "id", id).await;
+}
+
+pub async fn create_pool_announcement(id : &str, pool_id : &str, account_id : &str, text_content : &str, is_pinned : bool, creation_date : DateTime<Utc>, state : &AppState) -> () {
+    let is_pinned_string = is_pinned.to_string();
+    let is_pinned_str = is_pinned_string.as_str();
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("pool_announcements",
+    vec!["id", "pool_id", "account_id", "text_content", "is_pinned", "creation_date", "last_update_date"],
+    vec![vec![id, pool_id, account_id, text_content, is_pinned_str, creation_date_str, creation_date_str]]).await;
+}
+
+pub async fn set_pool_announcement_text_content_by_id(id : &str, text_content : &str, last_update_date : DateTime<Utc>, state : &AppState) -> () {
+    let last_update_date_string = last_update_date.to_rfc3339();
+    let last_update_date_str = last_update_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("pool_announcements", "id", id, vec!["text_content", "last_update_date"], vec![text_content, last_update_date_str]).await;
+}
+
+pub async fn set_pool_announcement_is_pinned_by_id(id : &str, is_pinned : bool, state : &AppState) -> () {
+    let is_pinned_string = is_pinned.to_string();
+    let is_pinned_str = is_pinned_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("pool_announcements", "id", id, vec!["is_pinned"], vec![is_pinned_str]).await;
+}
+
+pub async fn delete_pool_announcement_by_id(id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    db_service.delete_one_by_prop("pool_announcements", "id", id).await;
+}
\ No newline at end of file
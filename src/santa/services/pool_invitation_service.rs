@@ -0,0 +1,60 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::{functions::{get_one_item_from_command, render_query_template}, services::{IDbService, SQLiteDbService}}, santa::data_model::{implementations::PoolInvitation, traits::IPoolInvitation}, AppState};
+
+pub fn row_to_pool_invitation(row : &SqliteRow) -> PoolInvitation {
+    let id : &str = row.get("id");
+    let pool_id : &str = row.get("pool_id");
+    let account_id : &str = row.get("account_id");
+    let creation_date_str : &str = row.get("creation_date");
+    let creation_date : DateTime<Utc> = DateTime::from_str(creation_date_str).unwrap();
+    return PoolInvitation::new(id, pool_id, account_id, creation_date);
+}
+
+pub async fn get_pool_invitation_by_id(id : &str, state : &AppState) -> Option<impl IPoolInvitation> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_one_by_prop("pool_invitations", "id", id, row_to_pool_invitation).await;
+}
+
+pub async fn get_pool_invitations_by_pool_id(pool_id : &str, state : &AppState) -> Option<Vec<impl IPoolInvitation>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("pool_invitations", "pool_id", vec![pool_id], row_to_pool_invitation).await;
+}
+
+pub async fn get_pool_invitations_by_account_id(account_id : &str, state : &AppState) -> Option<Vec<impl IPoolInvitation>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("pool_invitations", "account_id", vec![account_id], row_to_pool_invitation).await;
+}
+
+pub async fn get_pool_invitation_by_pool_and_account_ids(pool_id : &str, account_id : &str, state : &AppState) -> Option<impl IPoolInvitation> {
+    const GET_POOL_INVITATION_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE : &str = "database_scripts/pool_invitation/get_pool_invitation_by_pool_and_account_ids.sql";
+    let mut context = tera::Context::new();
+    context.insert("pool_id", &pool_id);
+    context.insert("account_id", &account_id);
+
+    let command = render_query_template(GET_POOL_INVITATION_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE, &context, &state).await;
+    return get_one_item_from_command(command.as_str(), state, row_to_pool_invitation).await;
+}
+
+pub async fn is_pool_invitation_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("pool_invitations", "id", id).await;
+}
+
+pub async fn create_pool_invitation(id : &str, pool_id : &str, account_id : &str, creation_date : DateTime<Utc>, state : &AppState) -> () {
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("pool_invitations",
+    vec!["id", "pool_id", "account_id", "creation_date"],
+    vec![vec![id, pool_id, account_id, creation_date_str]]).await;
+}
+
+pub async fn delete_pool_invitation_by_id(id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    db_service.delete_one_by_prop("pool_invitations", "id", id).await;
+}
\ No newline at end of file
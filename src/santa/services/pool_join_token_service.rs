@@ -0,0 +1,83 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::{functions::{execute_script_template_wo_return, get_many_rows_from_command, render_query_template}, services::{IDbService, SQLiteDbService}}, santa::data_model::{implementations::PoolJoinToken, traits::IPoolJoinToken}, AppState};
+
+// max_uses 0 means the token can be used any number of times
+pub fn row_to_pool_join_token(row : &SqliteRow) -> PoolJoinToken {
+    let id : &str = row.get("id");
+    let pool_id : &str = row.get("pool_id");
+    let token : &str = row.get("token");
+    let max_uses : u64 = row.get("max_uses");
+    let uses_count : u64 = row.get("uses_count");
+    let is_revoked_str : &str = row.get("is_revoked");
+    let is_revoked = is_revoked_str.to_lowercase() == "true";
+    let creation_date_str : &str = row.get("creation_date");
+    let creation_date : DateTime<Utc> = DateTime::from_str(creation_date_str).unwrap();
+    return PoolJoinToken::new(id, pool_id, token, max_uses, uses_count, is_revoked, creation_date);
+}
+
+pub async fn get_pool_join_token_by_id(id : &str, state : &AppState) -> Option<impl IPoolJoinToken> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_one_by_prop("pool_join_tokens", "id", id, row_to_pool_join_token).await;
+}
+
+pub async fn get_pool_join_token_by_token(token : &str, state : &AppState) -> Option<impl IPoolJoinToken> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_one_by_prop("pool_join_tokens", "token", token, row_to_pool_join_token).await;
+}
+
+pub async fn get_pool_join_tokens_by_pool_id(pool_id : &str, state : &AppState) -> Option<Vec<impl IPoolJoinToken>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("pool_join_tokens", "pool_id", vec![pool_id], row_to_pool_join_token).await;
+}
+
+pub async fn is_pool_join_token_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("pool_join_tokens", "id", id).await;
+}
+
+pub async fn is_pool_join_token_already_exists_by_token(token : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("pool_join_tokens", "token", token).await;
+}
+
+pub async fn create_pool_join_token(id : &str, pool_id : &str, token : &str, max_uses : u64, creation_date : DateTime<Utc>, state : &AppState) -> () {
+    let max_uses_string = max_uses.to_string();
+    let max_uses_str = max_uses_string.as_str();
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("pool_join_tokens",
+    vec!["id", "pool_id", "token", "max_uses", "uses_count", "is_revoked", "creation_date"],
+    vec![vec![id, pool_id, token, max_uses_str, "0", "false", creation_date_str]]).await;
+}
+
+// checking and taking a use is one statement, so two users can not take the last use at once
+pub async fn take_pool_join_token_use_by_id(id : &str, state : &AppState) -> bool {
+    const TAKE_POOL_JOIN_TOKEN_USE_TEMPLATE : &str = "database_scripts/pool_join_token/take_pool_join_token_use.sql";
+    let mut context = tera::Context::new();
+    context.insert("id", &id);
+
+    let command = render_query_template(TAKE_POOL_JOIN_TOKEN_USE_TEMPLATE, &context, &state).await;
+    let rows = get_many_rows_from_command(command.as_str(), state, |row| {
+        let uses_count : u64 = row.get("uses_count");
+        uses_count
+    }).await.unwrap_or(vec![]);
+    return !rows.is_empty();
+}
+
+pub async fn return_pool_join_token_use_by_id(id : &str, state : &AppState) -> () {
+    const RETURN_POOL_JOIN_TOKEN_USE_TEMPLATE : &str = "database_scripts/pool_join_token/return_pool_join_token_use.sql";
+    let mut context = tera::Context::new();
+    context.insert("id", &id);
+    execute_script_template_wo_return(RETURN_POOL_JOIN_TOKEN_USE_TEMPLATE, &context, &state).await;
+}
+
+pub async fn revoke_pool_join_token_by_id(id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("pool_join_tokens", "id", id, vec!["is_revoked"], vec!["true"]).await;
+}
\ No newline at end of file
@@ -0,0 +1,55 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::{functions::{get_one_item_from_command, render_query_template}, services::{IDbService, SQLiteDbService}}, santa::data_model::{implementations::PoolOrganizer, traits::IPoolOrganizer}, AppState};
+
+pub fn row_to_pool_organizer(row : &SqliteRow) -> PoolOrganizer {
+    let id : &str = row.get("id");
+    let pool_id : &str = row.get("pool_id");
+    let account_id : &str = row.get("account_id");
+    let creation_date_str : &str = row.get("creation_date");
+    let creation_date : DateTime<Utc> = DateTime::from_str(creation_date_str).unwrap();
+    return PoolOrganizer::new(id, pool_id, account_id, creation_date);
+}
+
+pub async fn get_pool_organizers_by_pool_id(pool_id : &str, state : &AppState) -> Option<Vec<impl IPoolOrganizer>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("pool_organizers", "pool_id", vec![pool_id], row_to_pool_organizer).await;
+}
+
+pub async fn get_pool_organizers_by_account_id(account_id : &str, state : &AppState) -> Option<Vec<impl IPoolOrganizer>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("pool_organizers", "account_id", vec![account_id], row_to_pool_organizer).await;
+}
+
+pub async fn get_pool_organizer_by_pool_and_account_ids(pool_id : &str, account_id : &str, state : &AppState) -> Option<impl IPoolOrganizer> {
+    const GET_POOL_ORGANIZER_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE : &str = "database_scripts/pool_organizer/get_pool_organizer_by_pool_and_account_ids.sql";
+    let mut context = tera::Context::new();
+    context.insert("pool_id", &pool_id);
+    context.insert("account_id", &account_id);
+
+    let command = render_query_template(GET_POOL_ORGANIZER_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE, &context, &state).await;
+    return get_one_item_from_command(command.as_str(), state, row_to_pool_organizer).await;
+}
+
+pub async fn is_pool_organizer_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("pool_organizers", "id", id).await;
+}
+
+pub async fn create_pool_organizer(id : &str, pool_id : &str, account_id : &str, creation_date : DateTime<Utc>, state : &AppState) -> () {
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("pool_organizers",
+    vec!["id", "pool_id", "account_id", "creation_date"],
+    vec![vec![id, pool_id, account_id, creation_date_str]]).await;
+}
+
+pub async fn delete_pool_organizer_by_id(id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    db_service.delete_one_by_prop("pool_organizers", "id", id).await;
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::services::{IDbService, SQLiteDbService}, santa::data_model::{implementations::PoolQuestion, traits::IPoolQuestion}, AppState};
+
+pub fn row_to_pool_question(row : &SqliteRow) -> PoolQuestion {
+    let id : &str = row.get("id");
+    let pool_id : &str = row.get("pool_id");
+    let text_content : &str = row.get("text_content");
+    let is_required_str : &str = row.get("is_required");
+    let is_required = is_required_str.to_lowercase() == "true";
+    let position : u64 = row.get("position");
+    return PoolQuestion::new(id, pool_id, text_content, is_required, position);
+}
+
+pub async fn get_pool_question_by_id(id : &str, state : &AppState) -> Option<impl IPoolQuestion> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_one_by_prop("pool_questions", "id", id, row_to_pool_question).await;
+}
+
+pub async fn get_pool_questions_by_pool_id(pool_id : &str, state : &AppState) -> Option<Vec<impl IPoolQuestion>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("pool_questions", "pool_id", vec![pool_id], row_to_pool_question).await;
+}
+
+pub async fn is_pool_question_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("pool_questions", "id", id).await;
+}
+
+pub async fn create_pool_question(id : &str, pool_id : &str, text_content : &str, is_required : bool, position : u64, state : &AppState) -> () {
+    let is_required_string = is_required.to_string();
+    let is_required_str = is_required_string.as_str();
+    let position_string = position.to_string();
+    let position_str = position_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("pool_questions",
+    vec!["id", "pool_id", "text_content", "is_required", "position"],
+    vec![vec![id, pool_id, text_content, is_required_str, position_str]]).await;
+}
+
+pub async fn delete_pool_question_by_id(id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    db_service.delete_one_by_prop("pool_questions", "id", id).await;
+}
\ No newline at end of file
@@ -3,7 +3,7 @@ use std::str::FromStr;
 use chrono::{DateTime, Utc};
 use sqlx::{sqlite::SqliteRow, Row};
 
-use crate::{core::services::{IDbService, SQLiteDbService}, santa::data_model::{enums::PoolState, implementations::Pool, traits::IPool}, AppState};
+use crate::{core::services::{IDbService, SQLiteDbService}, santa::data_model::{enums::{PoolState, PoolVisibility, RoomState}, implementations::Pool, traits::IPool}, AppState};
 
 pub fn row_to_pool(row : &SqliteRow) -> Pool {
     let id : &str = row.get("id");
@@ -17,7 +17,28 @@ pub fn row_to_pool(row : &SqliteRow) -> Pool {
     let creation_date : DateTime<Utc> = DateTime::from_str(creation_date_str).unwrap();
     let pool_state_num : u8 = row.get("pool_state");
     let pool_state =  PoolState::try_from(usize::from(pool_state_num)).unwrap();
-    return Pool::new(id, name, description, account_id, min_price, max_price, creation_date, lifetime, pool_state);
+    let previous_pool_id : &str = row.get("previous_pool_id");
+    let history_depth : u64 = row.get("history_depth");
+    let gifts_per_member : u64 = row.get("gifts_per_member");
+    let cross_group_draw_str : &str = row.get("cross_group_draw");
+    let cross_group_draw = cross_group_draw_str.to_lowercase() == "true";
+    let end_room_state_num : u8 = row.get("end_room_state");
+    let end_room_state = RoomState::try_from(usize::from(end_room_state_num)).unwrap();
+    let open_at_str : &str = row.get("open_at");
+    let open_at : Option<DateTime<Utc>> = DateTime::from_str(open_at_str).ok();
+    let draw_at_str : &str = row.get("draw_at");
+    let draw_at : Option<DateTime<Utc>> = DateTime::from_str(draw_at_str).ok();
+    let end_at_str : &str = row.get("end_at");
+    let end_at : Option<DateTime<Utc>> = DateTime::from_str(end_at_str).ok();
+    let visibility_num : u8 = row.get("visibility");
+    let visibility = PoolVisibility::try_from(usize::from(visibility_num)).unwrap();
+    let max_members : u64 = row.get("max_members");
+    let currency : &str = row.get("currency");
+    let archive_date_str : &str = row.get("archive_date");
+    let archive_date : Option<DateTime<Utc>> = DateTime::from_str(archive_date_str).ok();
+    let state_change_date_str : &str = row.get("state_change_date");
+    let state_change_date : DateTime<Utc> = DateTime::from_str(state_change_date_str).unwrap();
+    return Pool::new(id, name, description, account_id, min_price, max_price, creation_date, lifetime, pool_state, previous_pool_id, history_depth, gifts_per_member, cross_group_draw, end_room_state, open_at, draw_at, end_at, visibility, max_members, currency, archive_date, state_change_date);
 }
 
 pub async fn get_pool_by_id(id : &str, state : &AppState) -> Option<impl IPool> {
@@ -34,9 +55,67 @@ pub async fn set_pool_state(id : &str, pool_state : PoolState, state : &AppState
     let pool_state_num = pool_state as usize;
     let pool_state_string = pool_state_num.to_string();
     let pool_state_str = pool_state_string.as_str();
+    let state_change_date_string = Utc::now().to_rfc3339();
+    let state_change_date_str = state_change_date_string.as_str();
 
     let db_service = SQLiteDbService::new(state);
-    db_service.update("pools", "id", id, vec!["pool_state"], vec![pool_state_str]).await;
+    db_service.update("pools", "id", id, vec!["pool_state", "state_change_date"], vec![pool_state_str, state_change_date_str]).await;
+}
+
+pub async fn set_pool_archived_by_id(id : &str, archive_date : DateTime<Utc>, state : &AppState) -> () {
+    let pool_state_num = PoolState::Archived as usize;
+    let pool_state_string = pool_state_num.to_string();
+    let pool_state_str = pool_state_string.as_str();
+    let archive_date_string = archive_date.to_rfc3339();
+    let archive_date_str = archive_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("pools", "id", id, vec!["pool_state", "archive_date", "state_change_date"], vec![pool_state_str, archive_date_str, archive_date_str]).await;
+}
+
+pub fn set_pool_state_query(id : &str, pool_state : PoolState, state : &AppState) -> String {
+    let pool_state_num = pool_state as usize;
+    let pool_state_string = pool_state_num.to_string();
+    let pool_state_str = pool_state_string.as_str();
+    let state_change_date_string = Utc::now().to_rfc3339();
+    let state_change_date_str = state_change_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    return db_service.update_query("pools", "id", id, vec!["pool_state", "state_change_date"], vec![pool_state_str, state_change_date_str]);
+}
+
+pub async fn set_pool_schedule_by_id(id : &str, open_at : Option<DateTime<Utc>>, draw_at : Option<DateTime<Utc>>, end_at : Option<DateTime<Utc>>, state : &AppState) -> () {
+    let open_at_string = open_at.map(|d| {d.to_rfc3339()}).unwrap_or_default();
+    let open_at_str = open_at_string.as_str();
+    let draw_at_string = draw_at.map(|d| {d.to_rfc3339()}).unwrap_or_default();
+    let draw_at_str = draw_at_string.as_str();
+    let end_at_string = end_at.map(|d| {d.to_rfc3339()}).unwrap_or_default();
+    let end_at_str = end_at_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("pools", "id", id, vec!["open_at", "draw_at", "end_at"], vec![open_at_str, draw_at_str, end_at_str]).await;
+}
+
+pub async fn set_pool_visibility_by_id(id : &str, visibility : PoolVisibility, state : &AppState) -> () {
+    let visibility_num = visibility as usize;
+    let visibility_string = visibility_num.to_string();
+    let visibility_str = visibility_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("pools", "id", id, vec!["visibility"], vec![visibility_str]).await;
+}
+
+pub async fn set_pool_owner_by_id(id : &str, account_id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("pools", "id", id, vec!["account_id"], vec![account_id]).await;
+}
+
+pub async fn set_pool_max_members_by_id(id : &str, max_members : u64, state : &AppState) -> () {
+    let max_members_string = max_members.to_string();
+    let max_members_str = max_members_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("pools", "id", id, vec!["max_members"], vec![max_members_str]).await;
 }
 
 pub async fn is_pool_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
@@ -44,7 +123,7 @@ pub async fn is_pool_already_exists_by_id(id : &str, state : &AppState) -> Optio
     return db_service.exists_by_prop("pools", "id", id).await;
 }
 
-pub async fn create_pool(id : &str, name : &str, description : &str, account_id : &str, min_price : u64, max_price : u64, lifetime : u64, creation_date : DateTime<Utc>, pool_state : PoolState,  state : &AppState) -> () {
+pub async fn create_pool(id : &str, name : &str, description : &str, account_id : &str, min_price : u64, max_price : u64, lifetime : u64, creation_date : DateTime<Utc>, pool_state : PoolState, previous_pool_id : &str, history_depth : u64, gifts_per_member : u64, cross_group_draw : bool, end_room_state : RoomState, open_at : Option<DateTime<Utc>>, draw_at : Option<DateTime<Utc>>, end_at : Option<DateTime<Utc>>, visibility : PoolVisibility, max_members : u64, currency : &str, archive_date : Option<DateTime<Utc>>, state_change_date : DateTime<Utc>, state : &AppState) -> () {
     let pool_state_num = pool_state as usize;
     
     let pool_state_string = pool_state_num.to_string();
@@ -57,11 +136,34 @@ pub async fn create_pool(id : &str, name : &str, description : &str, account_id
     let max_price_str = max_price_string.as_str();
     let lifetime_string = lifetime.to_string();
     let lifetime_str = lifetime_string.as_str();
+    let history_depth_string = history_depth.to_string();
+    let history_depth_str = history_depth_string.as_str();
+    let gifts_per_member_string = gifts_per_member.to_string();
+    let gifts_per_member_str = gifts_per_member_string.as_str();
+    let cross_group_draw_str = if cross_group_draw { "true" } else { "false" };
+    let end_room_state_num = end_room_state as usize;
+    let end_room_state_string = end_room_state_num.to_string();
+    let end_room_state_str = end_room_state_string.as_str();
+    let open_at_string = open_at.map(|d| {d.to_rfc3339()}).unwrap_or_default();
+    let open_at_str = open_at_string.as_str();
+    let draw_at_string = draw_at.map(|d| {d.to_rfc3339()}).unwrap_or_default();
+    let draw_at_str = draw_at_string.as_str();
+    let end_at_string = end_at.map(|d| {d.to_rfc3339()}).unwrap_or_default();
+    let end_at_str = end_at_string.as_str();
+    let visibility_num = visibility as usize;
+    let visibility_string = visibility_num.to_string();
+    let visibility_str = visibility_string.as_str();
+    let max_members_string = max_members.to_string();
+    let max_members_str = max_members_string.as_str();
+    let archive_date_string = archive_date.map(|d| {d.to_rfc3339()}).unwrap_or_default();
+    let archive_date_str = archive_date_string.as_str();
+    let state_change_date_string = state_change_date.to_rfc3339();
+    let state_change_date_str = state_change_date_string.as_str();
 
     let db_service = SQLiteDbService::new(state);
     let _ = db_service.insert("pools", 
-    vec!["id", "name", "description", "account_id", "min_price", "max_price", "lifetime", "creation_date", "pool_state"],
-    vec![vec![id, name, description, account_id, min_price_str, max_price_str, lifetime_str, creation_date_str, pool_state_str]]).await;
+    vec!["id", "name", "description", "account_id", "min_price", "max_price", "lifetime", "creation_date", "pool_state", "previous_pool_id", "history_depth", "gifts_per_member", "cross_group_draw", "end_room_state", "open_at", "draw_at", "end_at", "visibility", "max_members", "currency", "archive_date", "state_change_date"],
+    vec![vec![id, name, description, account_id, min_price_str, max_price_str, lifetime_str, creation_date_str, pool_state_str, previous_pool_id, history_depth_str, gifts_per_member_str, cross_group_draw_str, end_room_state_str, open_at_str, draw_at_str, end_at_str, visibility_str, max_members_str, currency, archive_date_str, state_change_date_str]]).await;
 }
 
 pub async fn delete_pool_by_id(id : &str, state : &AppState) -> () {
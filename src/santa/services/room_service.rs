@@ -1,6 +1,6 @@
 use sqlx::{sqlite::SqliteRow, Row};
 
-use crate::{core::services::{IDbService, SQLiteDbService}, santa::data_model::{enums::RoomState, implementations::Room, traits::IRoom}, AppState};
+use crate::{core::{functions::{get_many_items_from_command, get_many_rows_from_command, render_query_template}, services::{IDbService, SQLiteDbService}}, santa::data_model::{enums::RoomState, implementations::Room, traits::IRoom}, AppState};
 
 pub fn row_to_room(row : &SqliteRow) -> Room {
     let id : &str = row.get("id");
@@ -37,6 +37,30 @@ pub async fn get_rooms_by_user(account_id : &str, state : &AppState) -> Option<V
     return Some(result);
 }
 
+// a member has a room for every gift they give and every gift they receive
+pub async fn get_rooms_by_pool_and_account_ids(pool_id : &str, account_id : &str, state : &AppState) -> Option<Vec<impl IRoom>> {
+    const GET_ROOMS_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE : &str = "database_scripts/room/get_rooms_by_pool_and_account_ids.sql";
+    let mut context = tera::Context::new();
+    context.insert("pool_id", &pool_id);
+    context.insert("account_id", &account_id);
+
+    let command = render_query_template(GET_ROOMS_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE, &context, &state).await;
+    return get_many_items_from_command(command.as_str(), state, row_to_room).await;
+}
+
+pub async fn count_rooms_by_pool_id_per_state(pool_id : &str, state : &AppState) -> Option<Vec<(RoomState, u64)>> {
+    const COUNT_ROOMS_BY_POOL_ID_PER_STATE_TEMPLATE : &str = "database_scripts/room/count_rooms_by_pool_id_per_state.sql";
+    let mut context = tera::Context::new();
+    context.insert("pool_id", &pool_id);
+
+    let command = render_query_template(COUNT_ROOMS_BY_POOL_ID_PER_STATE_TEMPLATE, &context, &state).await;
+    return get_many_rows_from_command(command.as_str(), state, |row| {
+        let room_state_num : u8 = row.get("room_state");
+        let room_count : u64 = row.get("room_count");
+        (RoomState::try_from(usize::from(room_state_num)).unwrap(), room_count)
+    }).await;
+}
+
 pub async fn is_room_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
    let db_service = SQLiteDbService::new(state);
    return db_service.exists_by_prop("rooms", "id", id).await;
@@ -53,6 +77,17 @@ pub async fn create_room(id : &str, pool_id : &str, mailer_id : &str, recipient_
     vec![vec![id, pool_id, mailer_id, recipient_id, room_state_str]]).await;
 }
 
+pub fn create_room_query(id : &str, pool_id : &str, mailer_id : &str, recipient_id : &str, room_state : RoomState, state : &AppState) -> String {
+    let room_state_num = room_state as usize;
+    let room_state_string = room_state_num.to_string();
+    let room_state_str = room_state_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    return db_service.insert_query("rooms",
+    vec!["id", "pool_id", "mailer_id", "recipient_id", "room_state"],
+    vec![vec![id, pool_id, mailer_id, recipient_id, room_state_str]]);
+}
+
 pub async fn set_room_state_by_id(id : &str, room_state : RoomState, state : &AppState) -> () {
     let room_state_num = room_state as usize;
     let room_state_string = room_state_num.to_string();
@@ -62,6 +97,25 @@ pub async fn set_room_state_by_id(id : &str, room_state : RoomState, state : &Ap
     db_service.update("rooms", "id", id, vec!["room_state"], vec![room_state_str]).await;
 }
 
+pub fn set_room_state_query(id : &str, room_state : RoomState, state : &AppState) -> String {
+    let room_state_num = room_state as usize;
+    let room_state_string = room_state_num.to_string();
+    let room_state_str = room_state_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    return db_service.update_query("rooms", "id", id, vec!["room_state"], vec![room_state_str]);
+}
+
+pub fn set_room_mailer_id_query(id : &str, mailer_id : &str, state : &AppState) -> String {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.update_query("rooms", "id", id, vec!["mailer_id"], vec![mailer_id]);
+}
+
+pub fn delete_room_by_id_query(id : &str, state : &AppState) -> String {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.delete_query("rooms", "id", vec![id]);
+}
+
 pub async fn delete_room_by_id(id : &str, state : &AppState) -> () {
     let db_service = SQLiteDbService::new(state);
     db_service.delete_one_by_prop("rooms", "id", id).await;
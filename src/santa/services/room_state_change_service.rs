@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::services::{IDbService, SQLiteDbService}, santa::data_model::{enums::RoomState, implementations::RoomStateChange, traits::IRoomStateChange}, AppState};
+
+pub fn row_to_room_state_change(row : &SqliteRow) -> RoomStateChange {
+    let id : &str = row.get("id");
+    let room_id : &str = row.get("room_id");
+    let pool_id : &str = row.get("pool_id");
+    let account_id : &str = row.get("account_id");
+    let room_state_num : u8 = row.get("room_state");
+    let room_state = RoomState::try_from(usize::from(room_state_num)).unwrap();
+    let creation_date_str : &str = row.get("creation_date");
+    let creation_date : DateTime<Utc> = DateTime::from_str(creation_date_str).unwrap();
+    return RoomStateChange::new(id, room_id, pool_id, account_id, room_state, creation_date);
+}
+
+pub async fn get_room_state_changes_by_room_id(room_id : &str, state : &AppState) -> Option<Vec<impl IRoomStateChange>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("room_state_changes", "room_id", vec![room_id], row_to_room_state_change).await;
+}
+
+pub async fn get_room_state_changes_by_pool_id(pool_id : &str, state : &AppState) -> Option<Vec<impl IRoomStateChange>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("room_state_changes", "pool_id", vec![pool_id], row_to_room_state_change).await;
+}
+
+pub async fn is_room_state_change_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("room_state_changes", "id", id).await;
+}
+
+pub async fn create_room_state_change(id : &str, room_id : &str, pool_id : &str, account_id : &str, room_state : RoomState, creation_date : DateTime<Utc>, state : &AppState) -> () {
+    let room_state_num = room_state as usize;
+    let room_state_string = room_state_num.to_string();
+    let room_state_str = room_state_string.as_str();
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("room_state_changes",
+    vec!["id", "room_id", "pool_id", "account_id", "room_state", "creation_date"],
+    vec![vec![id, room_id, pool_id, account_id, room_state_str, creation_date_str]]).await;
+}
+
+pub fn create_room_state_change_query(id : &str, room_id : &str, pool_id : &str, account_id : &str, room_state : RoomState, creation_date : DateTime<Utc>, state : &AppState) -> String {
+    let room_state_num = room_state as usize;
+    let room_state_string = room_state_num.to_string();
+    let room_state_str = room_state_string.as_str();
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    return db_service.insert_query("room_state_changes",
+    vec!["id", "room_id", "pool_id", "account_id", "room_state", "creation_date"],
+    vec![vec![id, room_id, pool_id, account_id, room_state_str, creation_date_str]]);
+}
\ No newline at end of file
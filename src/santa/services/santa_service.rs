@@ -1,10 +1,12 @@
-use chrono::{DateTime, Utc};
-use ::rand::{seq::SliceRandom, rng};
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use data_encoding::BASE64;
 use serde::{Deserialize, Serialize};
-use crate::{core::{controllers::{ApiResponse, ApiResponseStatus}, data_model::{implementations::PublicUserInfo, traits::{IAccountRelated, ILocalObject, IPublicUserInfo}}, functions::{get_many_items_from_command, new_id_safe, render_query_template}, services::{escape_string, get_public_user_info_by_account_id, is_account_already_exists_by_id, row_to_account, row_to_public_user_info, IDbService, SQLiteDbService}}, santa::{data_model::{enums::{PoolState, RoomState}, implementations::Pool, traits::{IMember, IMessage, IPool, IPoolRelated, IRoom}}, services::{create_member, create_message, create_pool, create_room, delete_member_by_id, get_last_messages_by_room_id, get_member_by_id, get_member_by_pool_and_account_ids, get_members_by_pool_id, get_pool_by_id, get_room_by_id, get_rooms_by_user, is_member_already_exists_by_id, is_member_already_exists_by_pool_and_account_ids, is_message_already_exists_by_id, is_pool_already_exists_by_id, is_room_already_exists_by_id, row_to_pool, set_member_room_id, set_pool_state, set_wishlist_by_id}}, AppState};
+use crate::{core::{controllers::{ApiResponse, ApiResponseStatus}, data_model::{implementations::PublicUserInfo, traits::{IAccountRelated, ILocalObject, IPublicUserInfo}}, functions::{generate_random_token, get_many_items_from_command, new_id_safe, render_query_template}, services::{escape_string, get_public_user_info_by_account_id, is_account_already_exists_by_id, row_to_account, row_to_public_user_info, IDbService, SQLiteDbService}}, santa::{data_model::{enums::{PoolState, PoolVisibility, RoomState}, implementations::{Exclusion, Pool}, traits::{IDrawProof, IExclusion, IMember, IMessage, IPool, IPoolRelated, IMemberAddress, IRoom, IRoomRelated, IRoomStateChange, IPoolJoinToken, IShipment, IStageDeadline, IThankYouNote, IWaitlistEntry, IPoolInvitation, IGiftSpend, IPoolAnnouncement, IPoolQuestion, IMemberAnswer}}, functions::{is_iso_currency_code, normalize_currency_code, decrypt_address, draw_commitment_input, encrypt_address, is_pair_allowed, make_draw, make_draw_commitment, match_mailers_to_recipients, pairs_from_string, pairs_to_string, rounds_from_string, rounds_to_string, DeliveryAddress}, services::{create_member_answer, create_pool_question, delete_member_answers_by_pool_and_account_ids, delete_pool_question_by_id, get_member_answers_by_pool_and_account_ids, get_pool_question_by_id, get_pool_questions_by_pool_id, is_member_answer_already_exists_by_id, is_pool_question_already_exists_by_id, create_pool_announcement, delete_pool_announcement_by_id, get_pool_announcement_by_id, get_pool_announcements_by_pool_id, is_pool_announcement_already_exists_by_id, set_pool_announcement_is_pinned_by_id, set_pool_announcement_text_content_by_id, count_members_by_pool_id, count_messages_by_pool_id_per_day, count_rooms_by_pool_id_per_state, create_gift_spend, get_gift_spend_by_room_id, get_gift_spends_by_pool_id, is_gift_spend_already_exists_by_id, set_gift_spend_by_id, create_pool_organizer, delete_pool_organizer_by_id, get_pool_organizer_by_pool_and_account_ids, get_pool_organizers_by_pool_id, is_pool_organizer_already_exists_by_id, set_pool_owner_by_id, create_pool_invitation, delete_pool_invitation_by_id, get_pool_invitation_by_id, get_pool_invitation_by_pool_and_account_ids, get_pool_invitations_by_account_id, get_pool_invitations_by_pool_id, is_pool_invitation_already_exists_by_id, create_waitlist_entry, delete_waitlist_entry_by_id, get_waitlist_entries_by_pool_id, get_waitlist_entry_by_pool_and_account_ids, is_waitlist_entry_already_exists_by_id, create_pool_join_token, get_pool_join_token_by_id, get_pool_join_token_by_token, get_pool_join_tokens_by_pool_id, is_pool_join_token_already_exists_by_id, is_pool_join_token_already_exists_by_token, return_pool_join_token_use_by_id, revoke_pool_join_token_by_id, take_pool_join_token_use_by_id, create_stage_deadline, delete_stage_deadlines_by_pool_id, get_stage_deadlines_by_pool_id, is_stage_deadline_already_exists_by_id, create_draw_proof, create_draw_proof_query, create_thank_you_note, get_thank_you_note_by_room_id, get_thank_you_notes_by_pool_id, is_thank_you_note_already_exists_by_id, set_thank_you_note_by_id, create_member_address, delete_member_address_by_id, delete_member_addresses_by_pool_id, get_member_address_by_pool_and_account_ids, is_member_address_already_exists_by_id, set_member_address_by_id, create_shipment, get_shipment_by_room_id, is_shipment_already_exists_by_id, set_shipment_details_by_room_id, create_room_state_change, create_room_state_change_query, is_room_state_change_already_exists_by_id, create_exclusion, create_member, create_message, create_pool, create_room, create_room_query, delete_draw_proofs_by_pool_id_query, delete_exclusion_by_id, delete_member_by_id, delete_member_by_id_query, delete_room_by_id_query, get_draw_proof_by_pool_id, get_exclusion_by_id, get_exclusions_by_pool_id, get_last_messages_by_room_id, get_member_by_id, get_member_by_pool_and_account_ids, get_members_by_pool_id, get_pool_by_id, get_room_by_id, get_room_state_changes_by_pool_id, get_room_state_changes_by_room_id, get_rooms_by_pool_and_account_ids, get_rooms_by_pool_id, get_rooms_by_user, is_draw_proof_already_exists_by_id, is_exclusion_already_exists_by_id, is_member_already_exists_by_id, is_member_already_exists_by_pool_and_account_ids, is_message_already_exists_by_id, is_pool_already_exists_by_id, is_room_already_exists_by_id, row_to_exclusion, row_to_member, row_to_pool, row_to_room, set_group_name_by_id, set_pool_schedule_by_id, set_pool_state, set_pool_visibility_by_id, set_pool_max_members_by_id, set_pool_state_query, set_pool_archived_by_id, set_room_mailer_id_query, set_room_state_query, set_wishlist_by_id}}, AppState};
 
 
-pub async fn user_create_pool(name : &str, description : &str, account_id : &str, min_price : u64, max_price : u64, state : &AppState) -> ApiResponse {
+pub async fn user_create_pool(name : &str, description : &str, account_id : &str, min_price : u64, max_price : u64, previous_pool_id : &str, history_depth : u64, gifts_per_member : u64, cross_group_draw : bool, end_room_state : RoomState, open_at : Option<DateTime<Utc>>, draw_at : Option<DateTime<Utc>>, end_at : Option<DateTime<Utc>>, visibility : PoolVisibility, max_members : u64, currency : &str, state : &AppState) -> ApiResponse {
     let creation_date = Utc::now();
     let new_id = new_id_safe(is_pool_already_exists_by_id, state).await;
     let pool_id = new_id.as_str();
@@ -14,15 +16,73 @@ pub async fn user_create_pool(name : &str, description : &str, account_id : &str
         let err_msg = format!("Pool with name \"{name}\" already exists");
         return ApiResponse::new(ApiResponseStatus::ERROR, serde_json::to_value(err_msg).unwrap());
     }
+    let price_err_opt = pool_price_range_error(min_price, max_price, currency);
+    if price_err_opt.is_some() {
+        return ApiResponse::error_from_str(price_err_opt.unwrap().as_str());
+    }
+    if gifts_per_member == 0 {
+        let err_msg = format!("Each member must give at least one gift");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let schedule_err_opt = pool_schedule_error(open_at, draw_at, end_at);
+    if schedule_err_opt.is_some() {
+        return ApiResponse::error_from_str(schedule_err_opt.unwrap().as_str());
+    }
+    if (end_room_state.clone() as usize) < (RoomState::GiftHasBeenDeliveredToRecipient as usize) {
+        let err_msg = format!("Pool can end automatically only after gifts have been delivered to recipients");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    if !previous_pool_id.is_empty() {
+        let previous_pool_opt = get_pool_by_id(previous_pool_id, state).await;
+        if previous_pool_opt.is_none() {
+            let err_msg = format!("Previous pool with id \"{previous_pool_id}\" not found");
+            return ApiResponse::error_from_str(err_msg.as_str());
+        }
+        let previous_pool = previous_pool_opt.unwrap();
+        if previous_pool.account_id() != account_id {
+            let err_msg = format!("Previous pool with id \"{previous_pool_id}\" belongs to another owner");
+            return ApiResponse::error_from_str(err_msg.as_str());
+        }
+    }
     let lifetime = state.config.lock().await.santa.pool_max_lifetime;
-    create_pool(pool_id, name, description, account_id, min_price, max_price, lifetime, creation_date, PoolState::Created, state).await;
+    create_pool(pool_id, name, description, account_id, min_price, max_price, lifetime, creation_date, PoolState::Created, previous_pool_id, history_depth, gifts_per_member, cross_group_draw, end_room_state, open_at, draw_at, end_at, visibility, max_members, currency, None, creation_date, state).await;
     return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(new_id).unwrap());
 }
 
+pub fn pool_price_range_error(min_price : u64, max_price : u64, currency : &str) -> Option<String> {
+    if !is_iso_currency_code(currency) {
+        return Some(format!("Currency \"{currency}\" is not a valid ISO 4217 code"));
+    }
+    if max_price == 0 {
+        return Some(format!("Maximum price must be greater than zero"));
+    }
+    if min_price > max_price {
+        return Some(format!("Minimum price {min_price} {currency} can not be greater than maximum price {max_price} {currency}"));
+    }
+    return None;
+}
+
+// scheduled times follow the pool stages, every set time must be later than the previous set one
+fn pool_schedule_error(open_at : Option<DateTime<Utc>>, draw_at : Option<DateTime<Utc>>, end_at : Option<DateTime<Utc>>) -> Option<String> {
+    let schedule = vec![("open_at", open_at), ("draw_at", draw_at), ("end_at", end_at)];
+    let mut previous_opt : Option<(&str, DateTime<Utc>)> = None;
+    for (name, time_opt) in schedule {
+        if time_opt.is_none() { continue; }
+        let time = time_opt.unwrap();
+        if previous_opt.is_some_and(|(_, previous_time)| {previous_time >= time}) {
+            let previous_name = previous_opt.unwrap().0;
+            return Some(format!("Scheduled time \"{name}\" must be later than \"{previous_name}\""));
+        }
+        previous_opt = Some((name, time));
+    }
+    return None;
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct MemberNickname {
     pub account_id : String,
-    pub nickname : String
+    pub nickname : String,
+    pub group_name : String
 }
 
 pub async fn user_get_member_nicknames_in_pool(pool_id : &str, state : &AppState) -> ApiResponse {
@@ -45,14 +105,15 @@ pub async fn user_get_member_nicknames_in_pool(pool_id : &str, state : &AppState
         let nickname = public_info.nickname(); 
         let info = MemberNickname {
             account_id : String::from(account_id),
-            nickname : String::from(nickname)
+            nickname : String::from(nickname),
+            group_name : String::from(member.group_name())
         };
         result.push(info);
     }
     return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
 }
 
-pub async fn user_add_member_to_pool(account_id : &str, pool_id : &str, wishlist : &str, state : &AppState) -> ApiResponse {
+pub async fn user_add_member_to_pool(account_id : &str, pool_id : &str, wishlist : &str, answers : &Vec<UserQuestionAnswer>, is_invited : bool, state : &AppState) -> ApiResponse {
     let account_exists = is_account_already_exists_by_id(account_id, state).await;
     if account_exists.is_none_or(|b| {!b}) {
         let err_msg = format!("Account with id \"{account_id}\" not found");
@@ -65,6 +126,10 @@ pub async fn user_add_member_to_pool(account_id : &str, pool_id : &str, wishlist
         return ApiResponse::new(ApiResponseStatus::ERROR, serde_json::to_value(err_msg).unwrap());
     }
     let pool = pool_opt.unwrap();
+    if PoolVisibility::Private == pool.visibility() && !is_invited && !is_pool_organizer(pool_id, account_id, state).await {
+        let err_msg = format!("Pool with id \"{pool_id}\" is private. It can be joined only through a join link");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
     if PoolState::Open != pool.state() {
         let err_msg = format!("State of pool with id \"{pool_id}\" does not allow add new members. Members addition is available only at the open stage");
         return ApiResponse::new(ApiResponseStatus::ERROR, serde_json::to_value(err_msg).unwrap());
@@ -78,7 +143,33 @@ pub async fn user_add_member_to_pool(account_id : &str, pool_id : &str, wishlist
         return ApiResponse::new(ApiResponseStatus::ERROR, serde_json::to_value(err_msg).unwrap());
     }
 
-    create_member(new_id.as_str(), account_id, "", pool_id, wishlist, state).await;
+    let members = get_members_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let is_pool_full = pool.max_members() > 0 && members.len() as u64 >= pool.max_members();
+    let waitlist_entry_opt = get_waitlist_entry_by_pool_and_account_ids(pool_id, account_id, state).await;
+    if is_pool_full && waitlist_entry_opt.is_some() {
+        let err_msg = format!("User with account id \"{account_id}\" is already on the waitlist of pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let questions = get_pool_questions_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let answers_error = questionnaire_answers_error(&questions, answers);
+    if answers_error.is_some() {
+        return ApiResponse::error_from_str(answers_error.unwrap().as_str());
+    }
+    save_member_answers(pool_id, account_id, answers, state).await;
+
+    if is_pool_full {
+        let waitlist_entry_id = new_id_safe(is_waitlist_entry_already_exists_by_id, state).await;
+        create_waitlist_entry(waitlist_entry_id.as_str(), pool_id, account_id, wishlist, Utc::now(), state).await;
+        let position = get_waitlist_entries_by_pool_id(pool_id, state).await.unwrap_or(vec![]).len();
+        let msg = format!("Pool with id \"{pool_id}\" is full. User with account id \"{account_id}\" was placed on the waitlist at position {position}");
+        return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+    }
+    if waitlist_entry_opt.is_some() {
+        delete_waitlist_entry_by_id(waitlist_entry_opt.unwrap().id(), state).await;
+    }
+
+    create_member(new_id.as_str(), account_id, pool_id, wishlist, "", state).await;
     return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(new_id).unwrap());
 }
 
@@ -98,7 +189,7 @@ pub async fn user_delete_member_from_pool(pool_id : &str, account_id : &str, sta
     }
     let pool = pool_opt.unwrap();
     if PoolState::Open != pool.state() {
-        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow remove members. Members removing is available only at the open stage");
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow remove members. Members removing is available only at the open stage, use member dropout at the started stage");
         return ApiResponse::new(ApiResponseStatus::ERROR, serde_json::to_value(err_msg).unwrap()); 
     }
     let member_option = get_member_by_pool_and_account_ids(pool_id, account_id, state).await;
@@ -110,11 +201,241 @@ pub async fn user_delete_member_from_pool(pool_id : &str, account_id : &str, sta
     let member = member_option.unwrap();
     let member_id = member.id();
     delete_member_by_id(member_id, state).await;
+    delete_member_address(pool_id, account_id, state).await;
+    delete_member_answers_by_pool_and_account_ids(pool_id, account_id, state).await;
+    promote_from_waitlist(pool_id, state).await;
     let msg = format!("Member with account id \"{account_id}\" was successfully deleted from pool with id \"{pool_id}\"");
     let resp = ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
     return resp; 
 }
 
+// messages without an author are posted by the service itself
+const SYSTEM_MESSAGE_ACCOUNT_ID : &str = "";
+
+// the dropped out member's santas take over their recipients. when a pair is not allowed or already exists,
+// the santa is swapped into a room that has not started buying a gift yet.
+// the recipients keep their rooms, only the mailer of the room is changed
+pub async fn user_drop_out_member_from_pool(pool_id : &str, account_id : &str, executor_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Started != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow member dropouts. Dropouts are available only at the started stage, use members removing at the open stage");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let member_opt = get_member_by_pool_and_account_ids(pool_id, account_id, state).await;
+    if member_opt.is_none() {
+        let err_msg = format!("Member with account id \"{account_id}\" and pool id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let member = member_opt.unwrap();
+
+    let db_service = SQLiteDbService::new(state);
+    let rooms = db_service.get_many_by_prop("rooms", "pool_id", vec![pool_id], row_to_room).await.unwrap_or(vec![]);
+    let exclusions = get_exclusions_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+
+    let dropout_rooms = get_rooms_by_pool_and_account_ids(pool_id, account_id, state).await.unwrap_or(vec![]);
+    let santa_ids : Vec<&str> = dropout_rooms.iter().filter(|r| {r.recipient_id() == account_id && r.mailer_id() != account_id}).map(|r| {r.mailer_id()}).collect();
+    let recipient_ids : Vec<&str> = dropout_rooms.iter().filter(|r| {r.mailer_id() == account_id && r.recipient_id() != account_id}).map(|r| {r.recipient_id()}).collect();
+    let dropout_room_by_recipient = |recipient_id : &str| {
+        dropout_rooms.iter().find(|r| {r.mailer_id() == account_id && r.recipient_id() == recipient_id}).map(|r| {r.id()}).unwrap()
+    };
+
+    // pairs of the remaining rooms can not be given once more
+    let mut forbidden = exclusions_to_forbidden_pairs(&exclusions);
+    for room in rooms.iter().filter(|r| {r.mailer_id() != account_id && r.recipient_id() != account_id}) {
+        forbidden.insert([String::from(room.mailer_id()), String::from(room.recipient_id())]);
+    }
+
+    let new_pairs = match_mailers_to_recipients(&santa_ids, &recipient_ids, &forbidden);
+    forbidden.extend(new_pairs.iter().cloned());
+    // room id and its new mailer id
+    let mut reassignments : Vec<(&str, String)> = new_pairs.iter().map(|p| {(dropout_room_by_recipient(p[1].as_str()), p[0].clone())}).collect();
+    let unmatched_santa_ids : Vec<&str> = santa_ids.iter().filter(|id| {!new_pairs.iter().any(|p| {p[0] == **id})}).cloned().collect();
+    let unmatched_recipient_ids : Vec<&str> = recipient_ids.iter().filter(|id| {!new_pairs.iter().any(|p| {p[1] == **id})}).cloned().collect();
+
+    let mut left_without_pair = Vec::<String>::new();
+    for (santa_id, recipient_id) in unmatched_santa_ids.iter().zip(unmatched_recipient_ids.iter()) {
+        let swap_room_opt = rooms.iter().find(|r| {
+            r.mailer_id() != account_id && r.recipient_id() != account_id &&
+            !reassignments.iter().any(|(room_id, _)| {*room_id == r.id()}) &&
+            RoomState::ChoosingAGift == r.room_state() &&
+            is_pair_allowed(santa_id, r.recipient_id(), &forbidden) &&
+            is_pair_allowed(r.mailer_id(), recipient_id, &forbidden)
+        });
+        if swap_room_opt.is_some() {
+            let swap_room = swap_room_opt.unwrap();
+            forbidden.insert([String::from(*santa_id), String::from(swap_room.recipient_id())]);
+            forbidden.insert([String::from(swap_room.mailer_id()), String::from(*recipient_id)]);
+            reassignments.push((swap_room.id(), String::from(*santa_id)));
+            reassignments.push((dropout_room_by_recipient(recipient_id), String::from(swap_room.mailer_id())));
+        } else if santa_id == recipient_id {
+            left_without_pair.push(String::from(*santa_id));
+        } else {
+            let nickname = get_nickname_or_account_id(account_id, state).await;
+            let err_msg = format!("Member \"{nickname}\" cannot drop out of pool with id \"{pool_id}\". Their santas can not take over their recipients without breaking an exclusion or repeating a pair, and there are no rooms left to swap with");
+            return ApiResponse::error_from_str(err_msg.as_str());
+        }
+    }
+
+    // rooms of the dropped out member as a recipient and rooms nobody took over are gone
+    let reassigned_room_ids : Vec<&str> = reassignments.iter().map(|(room_id, _)| {*room_id}).collect();
+    let removed_room_ids : Vec<&str> = dropout_rooms.iter().map(|r| {r.id()}).filter(|id| {!reassigned_room_ids.contains(id)}).collect();
+
+    let mut queries = Vec::<String>::new();
+    for room_id in &removed_room_ids {
+        queries.push(delete_room_by_id_query(room_id, state));
+    }
+    // the new mailer starts with choosing a gift, whatever the previous one has done
+    for (room_id, mailer_id) in &reassignments {
+        queries.push(set_room_mailer_id_query(room_id, mailer_id.as_str(), state));
+        queries.push(set_room_state_query(room_id, RoomState::ChoosingAGift, state));
+        let room_state_change_id = new_id_safe(is_room_state_change_already_exists_by_id, state).await;
+        queries.push(create_room_state_change_query(room_state_change_id.as_str(), room_id, pool_id, executor_id, RoomState::ChoosingAGift, Utc::now(), state));
+    }
+    queries.push(delete_member_by_id_query(member.id(), state));
+
+    let transaction_result = db_service.execute_in_transaction(queries).await;
+    if transaction_result.is_err() {
+        let reason = transaction_result.err().unwrap();
+        let err_msg = format!("Member with account id \"{account_id}\" did not drop out of pool with id \"{pool_id}\", nothing was changed. {reason}");
+        tracing::error!("{}", err_msg);
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    delete_member_address(pool_id, account_id, state).await;
+    delete_member_answers_by_pool_and_account_ids(pool_id, account_id, state).await;
+    db_service.delete_many_by_prop("room_state_changes", "room_id", removed_room_ids.clone()).await;
+    db_service.delete_many_by_prop("messages", "room_id", removed_room_ids.clone()).await;
+    let mut cleared_room_ids = removed_room_ids.clone();
+    cleared_room_ids.extend(reassigned_room_ids.iter());
+    db_service.delete_many_by_prop("shipments", "room_id", cleared_room_ids.clone()).await;
+    db_service.delete_many_by_prop("thank_you_notes", "room_id", cleared_room_ids.clone()).await;
+    db_service.delete_many_by_prop("gift_spends", "room_id", cleared_room_ids).await;
+
+    let text_content = "A member of the pool dropped out, the gift pairs were changed by the pool organizer. This room has a new mailer, they now choose a gift for the recipient of this room";
+    for room_id in &reassigned_room_ids {
+        let message_id = new_id_safe(is_message_already_exists_by_id, state).await;
+        create_message(message_id.as_str(), text_content, SYSTEM_MESSAGE_ACCOUNT_ID, room_id, pool_id, Utc::now(), state).await;
+    }
+
+    if !left_without_pair.is_empty() {
+        let mut nicknames = Vec::<String>::new();
+        for pair_account_id in &left_without_pair {
+            let nickname = get_nickname_or_account_id(pair_account_id, state).await;
+            nicknames.push(format!("\"{nickname}\""));
+        }
+        let nicknames_str = nicknames.join(", ");
+        let msg = format!("Member with account id \"{account_id}\" dropped out of pool with id \"{pool_id}\", but members {nicknames_str} were left with fewer gift pairs");
+        return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+    }
+    let msg = format!("Member with account id \"{account_id}\" dropped out of pool with id \"{pool_id}\"");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+pub async fn user_get_exclusions_in_pool(pool_id : &str, executor_id : &str, is_organizer : bool, state : &AppState) -> ApiResponse {
+    let pool_exists = is_pool_already_exists_by_id(pool_id, state).await;
+    if pool_exists.is_none_or(|b| {!b}) {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let db_service = SQLiteDbService::new(state);
+    let exclusions = db_service.get_many_by_prop("exclusions", "pool_id", vec![pool_id], row_to_exclusion).await.unwrap_or(vec![]);
+    if is_organizer {
+        return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(exclusions).unwrap());
+    }
+
+    let member_exists = is_member_already_exists_by_pool_and_account_ids(pool_id, executor_id, state).await;
+    if !member_exists {
+        let err_msg = format!("Account with id \"{executor_id}\" is not a member of the pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let result : Vec<Exclusion> = exclusions.into_iter().filter(|e| {e.first_account_id() == executor_id || e.second_account_id() == executor_id}).collect();
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+pub async fn user_add_exclusion_to_pool(pool_id : &str, executor_id : &str, first_account_id : &str, second_account_id : &str, is_organizer : bool, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Created != pool.state() && PoolState::Open != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow add exclusions. Exclusions addition is available only before the pooling stage");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    if first_account_id == second_account_id {
+        let err_msg = format!("Exclusion must contain two different accounts");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    if !is_organizer && executor_id != first_account_id && executor_id != second_account_id {
+        let err_msg = format!("Only pool owner can add exclusions that do not include themselves");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    for account_id in [first_account_id, second_account_id] {
+        let member_exists = is_member_already_exists_by_pool_and_account_ids(pool_id, account_id, state).await;
+        if !member_exists {
+            let err_msg = format!("Member with account id \"{account_id}\" and pool id \"{pool_id}\" not found");
+            return ApiResponse::error_from_str(err_msg.as_str());
+        }
+    }
+
+    let exclusions = get_exclusions_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let exclusion_exists = exclusions.iter().any(|e| {
+        (e.first_account_id() == first_account_id && e.second_account_id() == second_account_id) ||
+        (e.first_account_id() == second_account_id && e.second_account_id() == first_account_id)
+    });
+    if exclusion_exists {
+        let err_msg = format!("Exclusion for accounts with ids \"{first_account_id}\" and \"{second_account_id}\" already exists in pool with id \"{pool_id}\"");
+        return ApiResponse::new(ApiResponseStatus::WARNING, serde_json::to_value(err_msg).unwrap());
+    }
+
+    let new_id = new_id_safe(is_exclusion_already_exists_by_id, state).await;
+    create_exclusion(new_id.as_str(), executor_id, pool_id, first_account_id, second_account_id, state).await;
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(new_id).unwrap());
+}
+
+pub async fn user_delete_exclusion_from_pool(pool_id : &str, exclusion_id : &str, executor_id : &str, is_organizer : bool, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Created != pool.state() && PoolState::Open != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow remove exclusions. Exclusions removing is available only before the pooling stage");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let exclusion_opt = get_exclusion_by_id(exclusion_id, state).await;
+    if exclusion_opt.is_none() {
+        let msg = format!("Exclusion with id \"{exclusion_id}\" not found");
+        return ApiResponse::new(ApiResponseStatus::WARNING, serde_json::to_value(msg).unwrap());
+    }
+    let exclusion = exclusion_opt.unwrap();
+    if exclusion.pool_id() != pool_id {
+        let err_msg = format!("Exclusion with id \"{exclusion_id}\" does not belong to pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    if !is_organizer && exclusion.account_id() != executor_id {
+        let err_msg = format!("Only pool owner or exclusion author can remove exclusion with id \"{exclusion_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    delete_exclusion_by_id(exclusion_id, state).await;
+    let msg = format!("Exclusion with id \"{exclusion_id}\" was successfully deleted from pool with id \"{pool_id}\"");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
 pub async fn user_pool_state_push(pool_id : &str, state : &AppState) -> ApiResponse {
     let pool_option = get_pool_by_id(pool_id, state).await;
     if pool_option.is_none() { 
@@ -150,9 +471,19 @@ pub async fn user_pool_state_push(pool_id : &str, state : &AppState) -> ApiRespo
     }
     let next_pool_state = next_pool_state_option.unwrap();
     set_pool_state(pool_id, next_pool_state.clone(), state).await;
+    if next_pool_state == PoolState::Ended {
+        delete_member_addresses_by_pool_id(pool_id, state).await;
+    }
     if next_pool_state == PoolState::Pooling {
-        user_make_rooms(pool_id, state).await;
-        set_pool_state(pool_id, PoolState::Started, state).await;
+        let seed = get_pool_draw_seed(pool_id, state).await;
+
+        // rooms, the draw proof and the started state are saved in one transaction,
+        // so a failed draw leaves nothing behind and the pool goes back to open
+        let make_rooms_resp = user_make_rooms(pool_id, seed.as_str(), state).await;
+        if !make_rooms_resp.is_ok() {
+            set_pool_state(pool_id, PoolState::Open, state).await;
+            return make_rooms_resp;
+        }
     }
     let msg = format!("Pool with id \"{pool_id}\" changed state");
     return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());  
@@ -168,9 +499,58 @@ pub async fn user_delete_pool(pool_id : &str, state : &AppState) -> () {
    db_service.delete_many_by_prop("messages", "pool_id", del_list.to_vec()).await;
    db_service.delete_many_by_prop("rooms", "pool_id", del_list.to_vec()).await;
    db_service.delete_many_by_prop("members", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("member_addresses", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("exclusions", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("draw_proofs", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("room_state_changes", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("shipments", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("thank_you_notes", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("stage_deadlines", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("pool_join_tokens", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("waitlist_entries", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("pool_invitations", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("pool_organizers", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("gift_spends", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("pool_announcements", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("pool_questions", "pool_id", del_list.to_vec()).await;
+   db_service.delete_many_by_prop("member_answers", "pool_id", del_list.to_vec()).await;
    db_service.delete_one_by_prop("pools", "id", esc_pool_id).await;
 }
 
+// messages, addresses, questionnaire answers and delivery details go away, the pool, its members and rooms stay as history
+pub async fn archive_pool(pool_id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    let del_list = vec![pool_id];
+
+    db_service.delete_many_by_prop("messages", "pool_id", del_list.to_vec()).await;
+    db_service.delete_many_by_prop("member_addresses", "pool_id", del_list.to_vec()).await;
+    db_service.delete_many_by_prop("shipments", "pool_id", del_list.to_vec()).await;
+    db_service.delete_many_by_prop("pool_join_tokens", "pool_id", del_list.to_vec()).await;
+    db_service.delete_many_by_prop("waitlist_entries", "pool_id", del_list.to_vec()).await;
+    db_service.delete_many_by_prop("pool_invitations", "pool_id", del_list.to_vec()).await;
+    db_service.delete_many_by_prop("member_answers", "pool_id", del_list.to_vec()).await;
+    set_pool_archived_by_id(pool_id, Utc::now(), state).await;
+}
+
+pub async fn user_archive_pool(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Ended != pool.state() {
+        let err_msg = format!("Pool with id \"{pool_id}\" is not ended. Only ended pools can be archived");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    archive_pool(pool_id, state).await;
+    let msg = format!("Pool with id \"{pool_id}\" was archived");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+// expired ended pools are archived first, archived ones are purged once their retention is over.
+// pools that never ended are deleted at once as before, archiving would wipe a running event
 pub async fn delete_pools_if_lifetime(state : &AppState) -> () {
     const GET_POOLS_WITH_EXPIRED_LIFETIME_TEMPLATE : &str = "database_scripts/pool/get_pools_with_expired_lifetime.sql";
     let now_time = Utc::now();
@@ -182,15 +562,101 @@ pub async fn delete_pools_if_lifetime(state : &AppState) -> () {
     
     let query = render_query_template(GET_POOLS_WITH_EXPIRED_LIFETIME_TEMPLATE, &context, state).await;
 
-    let pools_opt = get_many_items_from_command(query.as_str(), state, row_to_pool).await;
-    if pools_opt.is_none() {
-        return;
+    let pools = get_many_items_from_command(query.as_str(), state, row_to_pool).await.unwrap_or(vec![]);
+    for pool in pools.iter().filter(|p| {PoolState::Archived != p.state()}) {
+        let pool_id = pool.id();
+        if PoolState::Ended == pool.state() {
+            archive_pool(pool_id, state).await;
+            tracing::info!("Pool with id \"{pool_id}\" reached its lifetime and was archived");
+        } else {
+            user_delete_pool(pool_id, state).await;
+            tracing::info!("Pool with id \"{pool_id}\" reached its lifetime and was deleted");
+        }
     }
-    let pools = pools_opt.unwrap();
 
-    for pool in pools {
+    let retention = state.config.lock().await.santa.archived_pool_retention;
+    let archived_state_string = (PoolState::Archived as usize).to_string();
+    let db_service = SQLiteDbService::new(state);
+    let archived_pools = db_service.get_many_by_prop("pools", "pool_state", vec![archived_state_string.as_str()], row_to_pool).await.unwrap_or(vec![]);
+    for pool in archived_pools {
+        // pools archived without a date, by a migration or by hand, count from their last state change
+        let archive_date = pool.archive_date().unwrap_or(pool.state_change_date());
+        if archive_date + TimeDelta::seconds(retention as i64) > now_time { continue; }
         let pool_id = pool.id();
         user_delete_pool(pool_id, state).await;
+        tracing::info!("Archived pool with id \"{pool_id}\" reached the end of its retention and was deleted");
+    }
+}
+
+pub async fn user_set_pool_schedule(pool_id : &str, open_at : Option<DateTime<Utc>>, draw_at : Option<DateTime<Utc>>, end_at : Option<DateTime<Utc>>, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Ended == pool.state() || PoolState::Archived == pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow changing the schedule. The pool already ended");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let schedule_err_opt = pool_schedule_error(open_at, draw_at, end_at);
+    if schedule_err_opt.is_some() {
+        return ApiResponse::error_from_str(schedule_err_opt.unwrap().as_str());
+    }
+
+    set_pool_schedule_by_id(pool_id, open_at, draw_at, end_at, state).await;
+    let msg = format!("Schedule of pool with id \"{pool_id}\" was saved");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+// every pool waiting for its scheduled time is pushed once the time comes,
+// a push that fails validation leaves the pool as it is and is tried again on the next run
+pub async fn push_pools_if_scheduled(state : &AppState) -> () {
+    let waiting_states = vec![PoolState::Created, PoolState::Open, PoolState::Started];
+    let waiting_state_strings : Vec<String> = waiting_states.into_iter().map(|s| {(s as usize).to_string()}).collect();
+    let db_service = SQLiteDbService::new(state);
+    let pools = db_service.get_many_by_prop("pools", "pool_state", waiting_state_strings.iter().map(|s| {s.as_str()}).collect(), row_to_pool).await.unwrap_or(vec![]);
+
+    let now = Utc::now();
+    for pool in pools {
+        let pool_id = pool.id();
+        let scheduled_at_opt = match pool.state() {
+            PoolState::Created => pool.open_at(),
+            PoolState::Open => pool.draw_at(),
+            PoolState::Started => pool.end_at(),
+            _ => None
+        };
+        if scheduled_at_opt.is_none_or(|scheduled_at| {scheduled_at > now}) { continue; }
+
+        let pool_state = pool.state();
+        let resp = user_pool_state_push(pool_id, state).await;
+        if resp.is_ok() {
+            tracing::info!("Pool with id \"{pool_id}\" was pushed from {pool_state:?} by schedule");
+        } else {
+            let reason = resp.body.as_str().unwrap_or_default();
+            tracing::warn!("Pool with id \"{pool_id}\" was not pushed from {pool_state:?} by schedule: {reason}");
+        }
+    }
+}
+
+// a started pool ends by itself once every room has reached the end state chosen by the owner
+pub async fn end_pools_if_all_gifts_delivered(state : &AppState) -> () {
+    let started_state_string = (PoolState::Started as usize).to_string();
+    let db_service = SQLiteDbService::new(state);
+    let pools = db_service.get_many_by_prop("pools", "pool_state", vec![started_state_string.as_str()], row_to_pool).await.unwrap_or(vec![]);
+
+    for pool in pools {
+        let pool_id = pool.id();
+        let rooms = get_rooms_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+        if rooms.is_empty() { continue; }
+        let end_room_state_num = pool.end_room_state() as usize;
+        let all_gifts_delivered = rooms.iter().all(|r| {(r.room_state() as usize) >= end_room_state_num});
+        if !all_gifts_delivered { continue; }
+
+        let resp = user_pool_state_push(pool_id, state).await;
+        if resp.is_ok() {
+            tracing::info!("Pool with id \"{pool_id}\" ended automatically");
+        }
     }
 }
 
@@ -202,7 +668,30 @@ pub struct UserRoomResponse {
     pub room_state : RoomState,
     pub recipient_nickname : String,
     pub pool_name : String,
-    pub recipient_wishlist : String
+    pub recipient_wishlist : String,
+    pub recipient_answers : Vec<UserMemberAnswerResponse>,
+    pub shipment : Option<UserShipmentResponse>,
+    pub is_overdue : bool
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserShipmentResponse {
+    pub carrier_name : String,
+    pub tracking_number : String,
+    pub expected_delivery_date : Option<NaiveDate>,
+    pub last_update_date : DateTime<Utc>
+}
+
+async fn get_room_shipment_response(room_id : &str, state : &AppState) -> Option<UserShipmentResponse> {
+    let shipment_opt = get_shipment_by_room_id(room_id, state).await;
+    if shipment_opt.is_none() { return None; }
+    let shipment = shipment_opt.unwrap();
+    return Some(UserShipmentResponse {
+        carrier_name: String::from(shipment.carrier_name()),
+        tracking_number: String::from(shipment.tracking_number()),
+        expected_delivery_date: shipment.expected_delivery_date(),
+        last_update_date: shipment.last_update_date()
+    });
 }
 
 pub async fn user_get_rooms_by_user(account_id : &str, state : &AppState) -> ApiResponse {
@@ -282,7 +771,10 @@ pub async fn user_get_rooms_by_user(account_id : &str, state : &AppState) -> Api
             room_state: room.room_state(),
             recipient_nickname: String::from(recipient_nickname),
             pool_name: String::from(pool_name),
-            recipient_wishlist: String::from(recipient_wishlist)
+            recipient_wishlist: String::from(recipient_wishlist),
+            recipient_answers: get_member_answers_response(pool_id, recipient_id, state).await,
+            shipment: get_room_shipment_response(room.id(), state).await,
+            is_overdue: is_room_overdue(pool_id, pool.state(), room.room_state(), state).await
         };
         result.push(temp);
         
@@ -329,7 +821,10 @@ pub async fn user_get_room_info_by_id(room_id : &str, state : &AppState) -> ApiR
             room_state: room.room_state(),
             recipient_nickname: String::from(recipient_nickname),
             pool_name: String::from(pool_name),
-            recipient_wishlist: String::from(recipient_wishlist)
+            recipient_wishlist: String::from(recipient_wishlist),
+            recipient_answers: get_member_answers_response(pool_id, recipient_id, state).await,
+            shipment: get_room_shipment_response(room.id(), state).await,
+            is_overdue: is_room_overdue(pool_id, pool.state(), room.room_state(), state).await
         };
         return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
 }
@@ -443,117 +938,2422 @@ pub async fn user_create_room_for_members(member_mailer_id : &str, member_recipi
     let new_id = new_id_safe(is_room_already_exists_by_id, state).await;
     let room_id = new_id.as_str();
     create_room(room_id, pool_id, member_mailer.account_id(), member_recipient.account_id(), RoomState::ChoosingAGift, state).await;
-    set_member_room_id(member_mailer_id, room_id, state).await;
-    set_member_room_id(member_recipient_id, room_id, state).await;
+    let room_state_change_id = new_id_safe(is_room_state_change_already_exists_by_id, state).await;
+    create_room_state_change(room_state_change_id.as_str(), room_id, pool_id, "", RoomState::ChoosingAGift, Utc::now(), state).await;
     return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(new_id).unwrap());
 }
 
-fn make_pairs(vector : &Vec<&str>) -> Vec<[String; 2]> {
-   let mut mix = vector.clone();
-   mix.shuffle(&mut rng());
-   mix.push(mix[0]);
-   let mut result : Vec<[String; 2]> = Vec::new();
-   let n = mix.len();
-   for i in 0..(n - 1) {
-        let temp : [String; 2] = [String::from(mix[i]), String::from(mix[i+1])];
-        result.push(temp);
-   }
-   return result;
+async fn get_nickname_or_account_id(account_id : &str, state : &AppState) -> String {
+    let public_info_opt = get_public_user_info_by_account_id(account_id, state).await;
+    if public_info_opt.is_none() { return String::from(account_id); }
+    return String::from(public_info_opt.unwrap().nickname());
 }
 
-pub async fn user_make_rooms(pool_id : &str, state : &AppState) -> () {
-    let pool_option = get_pool_by_id(pool_id, state).await;
-    if pool_option.is_none() { return; }
-    let pool = pool_option.unwrap();
-    if PoolState::Pooling != pool.state() { return; }
-    let members_option = get_members_by_pool_id(pool_id, state).await;
-    if members_option.is_none() { return; }
-    let members = members_option.unwrap();
-    let member_ids : Vec<&str> = members.iter().map(|m| -> &str {m.id()}).collect();
-    let pairs = make_pairs(&member_ids);
-    for pair in pairs {
-        let member_mailer_id = pair[0].as_str();
-        let member_recipient_id = pair[1].as_str();
-        user_create_room_for_members(member_mailer_id, member_recipient_id, state).await;
+// exclusions work both ways, so each one forbids two mailer -> recipient pairs
+fn exclusions_to_forbidden_pairs(exclusions : &Vec<impl IExclusion>) -> HashSet<[String; 2]> {
+    let mut forbidden = HashSet::<[String; 2]>::new();
+    for exclusion in exclusions {
+        let first = String::from(exclusion.first_account_id());
+        let second = String::from(exclusion.second_account_id());
+        forbidden.insert([first.clone(), second.clone()]);
+        forbidden.insert([second, first]);
     }
+    return forbidden;
 }
 
-#[derive(Serialize, Deserialize, Clone)]
-pub struct UserMessageResponse {
-    id : String,
-    text_content : String,
-    is_recipient : bool,
-    creation_date : DateTime<chrono::Utc>
-}
-
-pub async fn user_get_last_messages_by_room_id(room_id : &str, limit : usize, state : &AppState) -> ApiResponse {
-    let esc_room_id_string = escape_string(room_id);
-    let esc_room_id = esc_room_id_string.as_str();
-
-    let room_opt = get_room_by_id(esc_room_id, state).await;
-    if room_opt.is_none() {
-        let err_msg = format!("Room with id \"{esc_room_id}\" not found");
-        return ApiResponse::error_from_str(err_msg.as_str());
+// mailer -> recipient account id pairs of the last rounds, most recent round first
+async fn get_draw_history(pool : &impl IPool, account_ids : &Vec<&str>, state : &AppState) -> Vec<HashSet<[String; 2]>> {
+    let history_depth = pool.history_depth() as usize;
+    let mut history = Vec::<HashSet<[String; 2]>>::new();
+    if history_depth == 0 { return history; }
+
+    let mut history_pool_ids = Vec::<String>::new();
+    if !pool.previous_pool_id().is_empty() {
+        let mut previous_pool_id = String::from(pool.previous_pool_id());
+        while history_pool_ids.len() < history_depth && !previous_pool_id.is_empty() && previous_pool_id != pool.id() && !history_pool_ids.contains(&previous_pool_id) {
+            let previous_pool_opt = get_pool_by_id(previous_pool_id.as_str(), state).await;
+            if previous_pool_opt.is_none() { break; }
+            let previous_pool = previous_pool_opt.unwrap();
+            history_pool_ids.push(previous_pool_id);
+            previous_pool_id = String::from(previous_pool.previous_pool_id());
+        }
+    } else {
+        let db_service = SQLiteDbService::new(state);
+        let account_members = db_service.get_many_by_prop("members", "account_id", account_ids.clone(), row_to_member).await.unwrap_or(vec![]);
+        // without an explicit chain only pools shared by most of the current members count as earlier rounds
+        let mut shared_members_count = HashMap::<&str, usize>::new();
+        for member in account_members.iter().filter(|m| {m.pool_id() != pool.id()}) {
+            *shared_members_count.entry(member.pool_id()).or_insert(0) += 1;
+        }
+        let member_pool_ids : Vec<&str> = shared_members_count.iter()
+            .filter(|(_, &count)| {count >= 2 && count * 2 > account_ids.len()})
+            .map(|(&id, _)| {id}).collect();
+        if member_pool_ids.is_empty() { return history; }
+        let mut previous_pools : Vec<Pool> = db_service.get_many_by_prop("pools", "id", member_pool_ids, row_to_pool).await.unwrap_or(vec![]);
+        previous_pools.retain(|p| {p.creation_date() < pool.creation_date()});
+        previous_pools.sort_by(|a, b| {b.creation_date().cmp(&a.creation_date())});
+        history_pool_ids = previous_pools.iter().take(history_depth).map(|p| {String::from(p.id())}).collect();
     }
-    let room = room_opt.unwrap();
 
-    let messages = get_last_messages_by_room_id(esc_room_id, limit, state).await.unwrap_or(vec![]);
-    let mut result = Vec::<UserMessageResponse>::new();
-    for message in messages {
-        let is_recipient = message.account_id() == room.recipient_id();
-        let temp = UserMessageResponse {
-            id : String::from(message.id()),
-            text_content : String::from(message.text_content()),
-            is_recipient : is_recipient,
-            creation_date : message.creation_date() 
-        };
-        result.push(temp);
+    for history_pool_id in &history_pool_ids {
+        let rooms = get_rooms_by_pool_id(history_pool_id.as_str(), state).await.unwrap_or(vec![]);
+        let round : HashSet<[String; 2]> = rooms.iter().map(|r| {[String::from(r.mailer_id()), String::from(r.recipient_id())]}).collect();
+        if round.is_empty() { continue; }
+        history.push(round);
     }
-    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+    return history;
 }
 
-pub async fn user_send_message_to_room2(room_id : &str, account_id : &str, text_content : &str, state : &AppState) -> ApiResponse {
-    let esc_room_id_string = escape_string(room_id);
-    let esc_room_id = esc_room_id_string.as_str();
+// the seed is chosen once per pool, so previews and the real draw give the same outcome.
+// until the draw it is kept in a draw proof without a commitment
+async fn get_pool_draw_seed(pool_id : &str, state : &AppState) -> String {
+    let draw_proof_opt = get_draw_proof_by_pool_id(pool_id, state).await;
+    if draw_proof_opt.is_some() { return String::from(draw_proof_opt.unwrap().seed()); }
 
-    let room_opt = get_room_by_id(esc_room_id, state).await;
-    if room_opt.is_none() {
-        let err_msg = format!("Room with id \"{esc_room_id}\" not found");
-        return  ApiResponse::error_from_str(err_msg.as_str());
+    let seed = generate_random_token();
+    let draw_proof_id = new_id_safe(is_draw_proof_already_exists_by_id, state).await;
+    create_draw_proof(draw_proof_id.as_str(), pool_id, seed.as_str(), "", "", "", "", "", Utc::now(), state).await;
+    return seed;
+}
+
+pub struct PoolDraw {
+    pub pairs : Vec<[String; 2]>,
+    pub forbidden : HashSet<[String; 2]>,
+    pub history : Vec<HashSet<[String; 2]>>,
+    pub respected_rounds : usize,
+    pub history_rounds : usize
+}
+
+// runs the draw for the current members and constraints of the pool without touching rooms,
+// the result depends only on the seed and the pool data
+pub async fn compute_pool_draw(pool_id : &str, seed : &str, member_account_ids : &Vec<&str>, is_cross_group : bool, state : &AppState) -> Result<PoolDraw, ApiResponse> {
+    let pool_option = get_pool_by_id(pool_id, state).await;
+    if pool_option.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return Err(ApiResponse::error_from_str(err_msg.as_str()));
     }
-    let room = room_opt.unwrap();
+    let pool = pool_option.unwrap();
+    let mut account_ids = member_account_ids.clone();
+    account_ids.sort();
 
+    let exclusions = get_exclusions_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let mut forbidden = exclusions_to_forbidden_pairs(&exclusions);
 
-    let esc_account_id_string = escape_string(account_id);
-    let esc_account_id = esc_account_id_string.as_str();
-    let account_exists = is_account_already_exists_by_id(esc_account_id, state).await;
-    if account_exists.is_none_or(|b| {!b}) {
-        let err_msg = format!("Account with id \"{esc_account_id}\" not found");
-        return  ApiResponse::error_from_str(err_msg.as_str());
+    // members of the same group can not gift each other, members without a group are not limited
+    let mut group_by_account_id = HashMap::<String, String>::new();
+    if is_cross_group {
+        let members = get_members_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+        for member in members.iter().filter(|m| {!m.group_name().is_empty()}) {
+            group_by_account_id.insert(String::from(member.account_id()), String::from(member.group_name()));
+        }
+        for first in &account_ids {
+            for second in &account_ids {
+                let first_group = group_by_account_id.get(*first);
+                if first != second && first_group.is_some() && first_group == group_by_account_id.get(*second) {
+                    forbidden.insert([String::from(*first), String::from(*second)]);
+                }
+            }
+        }
     }
 
-    if room.mailer_id() != esc_account_id && room.recipient_id() != esc_account_id {
-        let err_msg = format!("Account with id \"{esc_account_id}\" is not a member of room with id \"esc_room_id\"");
-        return  ApiResponse::error_from_str(err_msg.as_str());
+    let gifts_per_member = pool.gifts_per_member() as usize;
+    if account_ids.len() > 1 && gifts_per_member >= account_ids.len() {
+        let members_count = account_ids.len();
+        let err_msg = format!("Draw in pool with id \"{pool_id}\" is impossible. Each member has to give {gifts_per_member} gifts to different members, but there are only {members_count} members in the pool");
+        return Err(ApiResponse::error_from_str(err_msg.as_str()));
     }
 
-    let esc_text_content_string = escape_string(text_content);
-    let esc_text_content = esc_text_content_string.as_str();
+    let history = get_draw_history(&pool, &account_ids, state).await;
+    let (pairs_result, respected_rounds) = make_draw(&account_ids, &forbidden, &history, gifts_per_member, seed);
 
-    let message_content = esc_text_content.trim();
-    if message_content.is_empty() {
-        let err_msg = format!("Message body is empty");
-        return  ApiResponse::error_from_str(err_msg.as_str());
-    }
+    if pairs_result.is_err() {
+        let blocked_account_ids = pairs_result.err().unwrap();
 
-    let message_id_string = new_id_safe(is_message_already_exists_by_id, state).await;
-    let message_id = message_id_string.as_str();
+        let mut blocked_nicknames = Vec::<String>::new();
+        for account_id in &blocked_account_ids {
+            let nickname = get_nickname_or_account_id(account_id, state).await;
+            blocked_nicknames.push(format!("\"{nickname}\""));
+        }
 
-    let creation_date = Utc::now();
+        let mut conflicting_exclusions = Vec::<String>::new();
+        for exclusion in &exclusions {
+            let first_account_id = exclusion.first_account_id();
+            let second_account_id = exclusion.second_account_id();
+            if !blocked_account_ids.iter().any(|id| {id == first_account_id || id == second_account_id}) { continue; }
+            if !account_ids.contains(&first_account_id) || !account_ids.contains(&second_account_id) { continue; }
+            let first_nickname = get_nickname_or_account_id(first_account_id, state).await;
+            let second_nickname = get_nickname_or_account_id(second_account_id, state).await;
+            conflicting_exclusions.push(format!("\"{first_nickname}\" - \"{second_nickname}\""));
+        }
 
-    let pool_id = room.pool_id();
+        let blocked_str = blocked_nicknames.join(", ");
+        let conflicting_str = conflicting_exclusions.join(", ");
+        if !is_cross_group {
+            let err_msg = format!("Draw in pool with id \"{pool_id}\" is impossible. Exclusions leave members {blocked_str} with too few possible recipients between them to give {gifts_per_member} gifts each. Conflicting exclusions: {conflicting_str}");
+            return Err(ApiResponse::error_from_str(err_msg.as_str()));
+        }
 
-    create_message(message_id, message_content, esc_account_id, esc_room_id, pool_id, creation_date, state).await;
+        let mut blocked_groups = Vec::<&str>::new();
+        for account_id in &blocked_account_ids {
+            let group_opt = group_by_account_id.get(account_id);
+            if group_opt.is_some() && !blocked_groups.contains(&group_opt.unwrap().as_str()) {
+                blocked_groups.push(group_opt.unwrap().as_str());
+            }
+        }
+        let members_count = account_ids.len();
+        let blocked_groups_str = blocked_groups.iter().map(|group| {
+            let group_size = group_by_account_id.values().filter(|g| {g.as_str() == *group}).count();
+            format!("\"{group}\" ({group_size} of {members_count} members)")
+        }).collect::<Vec<String>>().join(", ");
+        let exclusions_str = if conflicting_exclusions.is_empty() { String::new() } else { format!(" Conflicting exclusions: {conflicting_str}.") };
+        let err_msg = format!("Draw in pool with id \"{pool_id}\" is impossible. Exclusions and cross-group rule leave members {blocked_str} with too few possible recipients between them to give {gifts_per_member} gifts each.{exclusions_str} Groups of these members: {blocked_groups_str}");
+        return Err(ApiResponse::error_from_str(err_msg.as_str()));
+    }
+
+    return Ok(PoolDraw {
+        pairs: pairs_result.unwrap(),
+        forbidden: forbidden,
+        history_rounds: history.len(),
+        history: history,
+        respected_rounds: respected_rounds
+    });
+}
+
+pub async fn user_make_rooms(pool_id : &str, seed : &str, state : &AppState) -> ApiResponse {
+    let pool_option = get_pool_by_id(pool_id, state).await;
+    if pool_option.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_option.unwrap();
+    if PoolState::Pooling != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow create new rooms. Room creation is available only at the pooling stage");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let members_option = get_members_by_pool_id(pool_id, state).await;
+    if members_option.is_none() {
+        let err_msg = format!("Can't get members from pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let members = members_option.unwrap();
+    let account_ids : Vec<&str> = members.iter().map(|m| {m.account_id()}).collect();
+
+    let draw_result = compute_pool_draw(pool_id, seed, &account_ids, pool.cross_group_draw(), state).await;
+    if draw_result.is_err() {
+        return draw_result.err().unwrap();
+    }
+    let draw = draw_result.ok().unwrap();
+
+    let mut queries = Vec::<String>::new();
+    for pair in &draw.pairs {
+        let mailer_id = pair[0].as_str();
+        let recipient_id = pair[1].as_str();
+        let new_id = new_id_safe(is_room_already_exists_by_id, state).await;
+        queries.push(create_room_query(new_id.as_str(), pool_id, mailer_id, recipient_id, RoomState::ChoosingAGift, state));
+        let room_state_change_id = new_id_safe(is_room_state_change_already_exists_by_id, state).await;
+        queries.push(create_room_state_change_query(room_state_change_id.as_str(), new_id.as_str(), pool_id, "", RoomState::ChoosingAGift, Utc::now(), state));
+    }
+    queries.push(set_pool_state_query(pool_id, PoolState::Started, state));
+
+    let mut sorted_account_ids = account_ids.clone();
+    sorted_account_ids.sort();
+    let forbidden_pairs = pairs_to_string(&draw.forbidden);
+    let history_pairs = rounds_to_string(&draw.history);
+    let pairs = pairs_to_string(&draw.pairs);
+    let commitment = make_draw_commitment(pool_id, seed, &account_ids, forbidden_pairs.as_str(), history_pairs.as_str(), pairs.as_str());
+    let draw_proof_id = new_id_safe(is_draw_proof_already_exists_by_id, state).await;
+    queries.push(delete_draw_proofs_by_pool_id_query(pool_id, state));
+    queries.push(create_draw_proof_query(draw_proof_id.as_str(), pool_id, seed, commitment.as_str(), sorted_account_ids.join(",").as_str(), forbidden_pairs.as_str(), history_pairs.as_str(), pairs.as_str(), Utc::now(), state));
+
+    let db_service = SQLiteDbService::new(state);
+    let transaction_result = db_service.execute_in_transaction(queries).await;
+    if transaction_result.is_err() {
+        let reason = transaction_result.err().unwrap();
+        let err_msg = format!("Rooms in pool with id \"{pool_id}\" were not created, nothing was saved. {reason}");
+        tracing::error!("{}", err_msg);
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    if draw.respected_rounds < draw.history_rounds {
+        let respected_rounds = draw.respected_rounds;
+        let history_rounds = draw.history_rounds;
+        let msg = format!("Rooms in pool with id \"{pool_id}\" were created, but only {respected_rounds} of {history_rounds} previous rounds could be avoided");
+        tracing::warn!("{}", msg);
+        return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+    }
+    let msg = format!("Rooms in pool with id \"{pool_id}\" were successfully created");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserDrawProofResponse {
+    pub pool_id : String,
+    pub commitment : String,
+    pub creation_date : DateTime<Utc>,
+    pub members : Vec<String>,
+    pub is_revealed : bool,
+    pub seed : Option<String>,
+    pub commitment_input : Option<String>,
+    pub is_commitment_valid : Option<bool>,
+    pub is_draw_reproduced : Option<bool>,
+    pub forbidden_pairs : Option<Vec<[String; 2]>>,
+    pub history_pairs : Option<Vec<Vec<[String; 2]>>>,
+    pub pairs : Option<Vec<[String; 2]>>
+}
+
+pub async fn user_get_draw_proof(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+
+    let draw_proof_opt = get_draw_proof_by_pool_id(pool_id, state).await;
+    if draw_proof_opt.as_ref().is_none_or(|p| {p.commitment().is_empty()}) {
+        let err_msg = format!("Draw in pool with id \"{pool_id}\" has not been made yet");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let draw_proof = draw_proof_opt.unwrap();
+    let members : Vec<&str> = draw_proof.members().split(",").filter(|m| {!m.is_empty()}).collect();
+
+    let mut result = UserDrawProofResponse {
+        pool_id: String::from(pool_id),
+        commitment: String::from(draw_proof.commitment()),
+        creation_date: draw_proof.creation_date(),
+        members: members.iter().map(|&m| {String::from(m)}).collect(),
+        is_revealed: false,
+        seed: None,
+        commitment_input: None,
+        is_commitment_valid: None,
+        is_draw_reproduced: None,
+        forbidden_pairs: None,
+        history_pairs: None,
+        pairs: None
+    };
+    if PoolState::Ended != pool.state() && PoolState::Archived != pool.state() {
+        return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+    }
+
+    // the draw is checked against the snapshot taken with it, not against the current pool data
+    let seed = draw_proof.seed();
+    let forbidden_pairs = draw_proof.forbidden_pairs();
+    let history_pairs = draw_proof.history_pairs();
+    let pairs = draw_proof.pairs();
+    let commitment = make_draw_commitment(pool_id, seed, &members, forbidden_pairs, history_pairs, pairs);
+    result.is_revealed = true;
+    result.seed = Some(String::from(seed));
+    result.commitment_input = Some(draw_commitment_input(pool_id, seed, &members, forbidden_pairs, history_pairs, pairs));
+    result.is_commitment_valid = Some(commitment == draw_proof.commitment());
+
+    let forbidden : HashSet<[String; 2]> = pairs_from_string(forbidden_pairs).into_iter().collect();
+    let history = rounds_from_string(history_pairs);
+    let (replay_result, _) = make_draw(&members, &forbidden, &history, pool.gifts_per_member() as usize, seed);
+    result.is_draw_reproduced = Some(replay_result.is_ok_and(|replay_pairs| {pairs_to_string(&replay_pairs) == pairs}));
+    result.forbidden_pairs = Some(pairs_from_string(forbidden_pairs));
+    result.history_pairs = Some(history.iter().map(|round| {pairs_from_string(pairs_to_string(round).as_str())}).collect());
+    result.pairs = Some(pairs_from_string(pairs));
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+pub async fn user_set_member_group_in_pool(pool_id : &str, account_id : &str, group_name : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Created != pool.state() && PoolState::Open != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow changing member groups. Groups can be changed only before the pooling stage");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let member_opt = get_member_by_pool_and_account_ids(pool_id, account_id, state).await;
+    if member_opt.is_none() {
+        let err_msg = format!("Member with account id \"{account_id}\" and pool id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let member = member_opt.unwrap();
+
+    let trimmed_group_name = group_name.trim();
+    set_group_name_by_id(member.id(), trimmed_group_name, state).await;
+    let msg = format!("Member with account id \"{account_id}\" was moved to group \"{trimmed_group_name}\" in pool with id \"{pool_id}\"");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GroupSize {
+    pub group_name : String,
+    pub members_count : usize
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserCrossGroupPreviewResponse {
+    pub pool_id : String,
+    pub is_cross_group_draw : bool,
+    pub is_possible : bool,
+    pub members_count : usize,
+    pub ungrouped_members_count : usize,
+    pub groups : Vec<GroupSize>,
+    pub reason : Option<String>
+}
+
+// checks whether a draw with the cross-group rule exists for the current members, nothing is saved
+pub async fn user_get_cross_group_preview(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+
+    let members = get_members_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let account_ids : Vec<&str> = members.iter().map(|m| {m.account_id()}).collect();
+    let mut groups = Vec::<GroupSize>::new();
+    for member in members.iter().filter(|m| {!m.group_name().is_empty()}) {
+        let group_opt = groups.iter_mut().find(|g| {g.group_name == member.group_name()});
+        if group_opt.is_some() {
+            group_opt.unwrap().members_count += 1;
+        } else {
+            groups.push(GroupSize { group_name: String::from(member.group_name()), members_count: 1 });
+        }
+    }
+    groups.sort_by(|a, b| {b.members_count.cmp(&a.members_count)});
+    let ungrouped_members_count = members.iter().filter(|m| {m.group_name().is_empty()}).count();
+
+    let seed = get_pool_draw_seed(pool_id, state).await;
+    let draw_result = compute_pool_draw(pool_id, seed.as_str(), &account_ids, true, state).await;
+    let reason = match &draw_result {
+        Ok(_) => None,
+        Err(resp) => Some(String::from(resp.body.as_str().unwrap_or("")))
+    };
+    let result = UserCrossGroupPreviewResponse {
+        pool_id: String::from(pool_id),
+        is_cross_group_draw: pool.cross_group_draw(),
+        is_possible: draw_result.is_ok(),
+        members_count: members.len(),
+        ungrouped_members_count: ungrouped_members_count,
+        groups: groups,
+        reason: reason
+    };
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DrawStatistics {
+    pub rooms_count : usize,
+    pub mutual_pairs_count : usize,
+    pub cycles_count : Option<usize>,
+    pub shortest_cycle_length : Option<usize>,
+    pub longest_cycle_length : Option<usize>
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserDrawPreviewResponse {
+    pub pool_id : String,
+    pub is_possible : bool,
+    pub members_count : usize,
+    pub gifts_per_member : u64,
+    pub exclusions_count : usize,
+    pub is_cross_group_draw : bool,
+    pub history_rounds : usize,
+    pub respected_history_rounds : usize,
+    pub violations : Vec<String>,
+    pub statistics : Option<DrawStatistics>
+}
+
+// cycles exist only when everyone gives one gift, with more gifts the pairs do not split into cycles
+fn draw_statistics(pairs : &Vec<[String; 2]>, gifts_per_member : u64) -> DrawStatistics {
+    let pair_set : HashSet<&[String; 2]> = pairs.iter().collect();
+    let mutual_pairs_count = pairs.iter().filter(|p| {
+        p[0] < p[1] && pair_set.contains(&[p[1].clone(), p[0].clone()])
+    }).count();
+
+    let mut cycle_lengths = Vec::<usize>::new();
+    if gifts_per_member == 1 {
+        let recipient_by_mailer : HashMap<&str, &str> = pairs.iter().map(|p| {(p[0].as_str(), p[1].as_str())}).collect();
+        let mut visited = HashSet::<&str>::new();
+        for pair in pairs {
+            let mut current = pair[0].as_str();
+            let mut length = 0;
+            while !visited.contains(current) {
+                visited.insert(current);
+                current = recipient_by_mailer[current];
+                length += 1;
+            }
+            if length > 0 { cycle_lengths.push(length); }
+        }
+    }
+    let has_cycles = gifts_per_member == 1;
+
+    return DrawStatistics {
+        rooms_count: pairs.len(),
+        mutual_pairs_count: mutual_pairs_count,
+        cycles_count: if has_cycles { Some(cycle_lengths.len()) } else { None },
+        shortest_cycle_length: if has_cycles { cycle_lengths.iter().min().copied() } else { None },
+        longest_cycle_length: if has_cycles { cycle_lengths.iter().max().copied() } else { None }
+    };
+}
+
+// the same draw as user_make_rooms with the same seed, only the outcome is reported, never the pairs
+pub async fn user_get_draw_preview(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Created != pool.state() && PoolState::Open != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow draw preview. Preview is available only before the pooling stage");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let members = get_members_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let account_ids : Vec<&str> = members.iter().map(|m| {m.account_id()}).collect();
+    let exclusions = get_exclusions_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+
+    let seed = get_pool_draw_seed(pool_id, state).await;
+    let draw_result = compute_pool_draw(pool_id, seed.as_str(), &account_ids, pool.cross_group_draw(), state).await;
+    let mut violations = Vec::<String>::new();
+    let mut history_rounds = 0;
+    let mut respected_history_rounds = 0;
+    let mut statistics = None;
+    match &draw_result {
+        Ok(draw) => {
+            history_rounds = draw.history_rounds;
+            respected_history_rounds = draw.respected_rounds;
+            if draw.respected_rounds < draw.history_rounds {
+                let skipped_rounds = draw.history_rounds - draw.respected_rounds;
+                violations.push(format!("Pairs from {skipped_rounds} oldest of {history_rounds} previous rounds may repeat, avoiding them leaves no possible draw"));
+            }
+            statistics = Some(draw_statistics(&draw.pairs, pool.gifts_per_member()));
+        },
+        Err(resp) => violations.push(String::from(resp.body.as_str().unwrap_or("")))
+    };
+
+    let result = UserDrawPreviewResponse {
+        pool_id: String::from(pool_id),
+        is_possible: draw_result.is_ok(),
+        members_count: members.len(),
+        gifts_per_member: pool.gifts_per_member(),
+        exclusions_count: exclusions.len(),
+        is_cross_group_draw: pool.cross_group_draw(),
+        history_rounds: history_rounds,
+        respected_history_rounds: respected_history_rounds,
+        violations: violations,
+        statistics: statistics
+    };
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserMessageResponse {
+    id : String,
+    text_content : String,
+    is_recipient : bool,
+    is_system : bool,
+    creation_date : DateTime<chrono::Utc>
+}
+
+pub async fn user_get_last_messages_by_room_id(room_id : &str, limit : usize, state : &AppState) -> ApiResponse {
+    let esc_room_id_string = escape_string(room_id);
+    let esc_room_id = esc_room_id_string.as_str();
+
+    let room_opt = get_room_by_id(esc_room_id, state).await;
+    if room_opt.is_none() {
+        let err_msg = format!("Room with id \"{esc_room_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let room = room_opt.unwrap();
+
+    let messages = get_last_messages_by_room_id(esc_room_id, limit, state).await.unwrap_or(vec![]);
+    let mut result = Vec::<UserMessageResponse>::new();
+    for message in messages {
+        let is_recipient = message.account_id() == room.recipient_id();
+        let is_system = message.account_id() == SYSTEM_MESSAGE_ACCOUNT_ID;
+        let temp = UserMessageResponse {
+            id : String::from(message.id()),
+            text_content : String::from(message.text_content()),
+            is_recipient : is_recipient,
+            is_system : is_system,
+            creation_date : message.creation_date() 
+        };
+        result.push(temp);
+    }
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+pub async fn user_send_message_to_room2(room_id : &str, account_id : &str, text_content : &str, state : &AppState) -> ApiResponse {
+    let esc_room_id_string = escape_string(room_id);
+    let esc_room_id = esc_room_id_string.as_str();
+
+    let room_opt = get_room_by_id(esc_room_id, state).await;
+    if room_opt.is_none() {
+        let err_msg = format!("Room with id \"{esc_room_id}\" not found");
+        return  ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let room = room_opt.unwrap();
+
+
+    let esc_account_id_string = escape_string(account_id);
+    let esc_account_id = esc_account_id_string.as_str();
+    let account_exists = is_account_already_exists_by_id(esc_account_id, state).await;
+    if account_exists.is_none_or(|b| {!b}) {
+        let err_msg = format!("Account with id \"{esc_account_id}\" not found");
+        return  ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    if room.mailer_id() != esc_account_id && room.recipient_id() != esc_account_id {
+        let err_msg = format!("Account with id \"{esc_account_id}\" is not a member of room with id \"esc_room_id\"");
+        return  ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let esc_text_content_string = escape_string(text_content);
+    let esc_text_content = esc_text_content_string.as_str();
+
+    let message_content = esc_text_content.trim();
+    if message_content.is_empty() {
+        let err_msg = format!("Message body is empty");
+        return  ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let message_id_string = new_id_safe(is_message_already_exists_by_id, state).await;
+    let message_id = message_id_string.as_str();
+
+    let creation_date = Utc::now();
+
+    let pool_id = room.pool_id();
+
+    create_message(message_id, message_content, esc_account_id, esc_room_id, pool_id, creation_date, state).await;
 
     return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(message_id).unwrap())
+}
+
+// the mailer leads the gift up to the delivery, the recipient confirms the last two steps
+pub async fn user_change_room_state(room_id : &str, account_id : &str, room_state : RoomState, is_moderator : bool, state : &AppState) -> ApiResponse {
+    let room_opt = get_room_by_id(room_id, state).await;
+    if room_opt.is_none() {
+        let err_msg = format!("Room with id \"{room_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let room = room_opt.unwrap();
+
+    let pool_id = room.pool_id();
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Started != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow change room states. Room states can be changed only when the pool is started");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let current_room_state = room.room_state();
+    let next_room_state_option = match current_room_state {
+        RoomState::ChoosingAGift => Some(RoomState::BuyingAGift),
+        RoomState::BuyingAGift => Some(RoomState::MailerAwaitingGiftDelivery),
+        RoomState::MailerAwaitingGiftDelivery => Some(RoomState::GiftDeliveredToMailer),
+        RoomState::GiftDeliveredToMailer => Some(RoomState::MailerSendGiftToRecipient),
+        RoomState::MailerSendGiftToRecipient => Some(RoomState::GiftInAWayToRecipient),
+        RoomState::GiftInAWayToRecipient => Some(RoomState::GiftHasBeenDeliveredToRecipient),
+        RoomState::GiftHasBeenDeliveredToRecipient => Some(RoomState::RecipientTookTheGift),
+        RoomState::RecipientTookTheGift => None
+    };
+    if next_room_state_option.is_none() {
+        let err_msg = format!("Room with id \"{room_id}\" is already in the final state {current_room_state:?}");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let next_room_state = next_room_state_option.unwrap();
+    if next_room_state != room_state {
+        let err_msg = format!("Room with id \"{room_id}\" can not change state from {current_room_state:?} to {room_state:?}. The only allowed next state is {next_room_state:?}");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let is_recipient_step = next_room_state == RoomState::GiftHasBeenDeliveredToRecipient || next_room_state == RoomState::RecipientTookTheGift;
+    let (side, side_account_id) = if is_recipient_step { ("recipient", room.recipient_id()) } else { ("mailer", room.mailer_id()) };
+    if !is_moderator && side_account_id != account_id {
+        let err_msg = format!("Only the {side} of room with id \"{room_id}\" can change its state to {next_room_state:?}");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let room_state_change_id = new_id_safe(is_room_state_change_already_exists_by_id, state).await;
+    let queries = vec![
+        set_room_state_query(room_id, next_room_state.clone(), state),
+        create_room_state_change_query(room_state_change_id.as_str(), room_id, pool_id, account_id, next_room_state.clone(), Utc::now(), state)
+    ];
+    let db_service = SQLiteDbService::new(state);
+    let transaction_result = db_service.execute_in_transaction(queries).await;
+    if transaction_result.is_err() {
+        let reason = transaction_result.err().unwrap();
+        let err_msg = format!("Room with id \"{room_id}\" did not change state, nothing was saved. {reason}");
+        tracing::error!("{}", err_msg);
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let msg = format!("Room with id \"{room_id}\" changed state to {next_room_state:?}");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RoomTimelineEntry {
+    pub room_state : RoomState,
+    pub changed_by : String,
+    pub creation_date : DateTime<Utc>,
+    pub duration_seconds : i64
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserRoomTimelineResponse {
+    pub room_id : String,
+    pub room_state : RoomState,
+    pub entries : Vec<RoomTimelineEntry>
+}
+
+// every stage lasts until the next change, the current one lasts until now
+pub async fn user_get_room_timeline(room_id : &str, state : &AppState) -> ApiResponse {
+    let room_opt = get_room_by_id(room_id, state).await;
+    if room_opt.is_none() {
+        let err_msg = format!("Room with id \"{room_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let room = room_opt.unwrap();
+
+    let mut changes = get_room_state_changes_by_room_id(room_id, state).await.unwrap_or(vec![]);
+    changes.sort_by(|a, b| {a.creation_date().cmp(&b.creation_date())});
+
+    let now = Utc::now();
+    let mut entries = Vec::<RoomTimelineEntry>::new();
+    for (i, change) in changes.iter().enumerate() {
+        let end_date = changes.get(i + 1).map(|c| {c.creation_date()}).unwrap_or(now);
+        let changed_by = if change.account_id() == room.mailer_id() {
+            "mailer"
+        } else if change.account_id() == room.recipient_id() {
+            "recipient"
+        } else {
+            "organizer"
+        };
+        entries.push(RoomTimelineEntry {
+            room_state: change.room_state(),
+            changed_by: String::from(changed_by),
+            creation_date: change.creation_date(),
+            duration_seconds: (end_date - change.creation_date()).num_seconds()
+        });
+    }
+
+    let result = UserRoomTimelineResponse {
+        room_id: String::from(room_id),
+        room_state: room.room_state(),
+        entries: entries
+    };
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RoomStageSummary {
+    pub room_state : RoomState,
+    pub rooms_count : usize,
+    pub average_seconds : Option<i64>,
+    pub longest_seconds : Option<i64>
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserPoolRoomStagesResponse {
+    pub pool_id : String,
+    pub rooms_count : usize,
+    pub stages : Vec<RoomStageSummary>
+}
+
+// how many rooms wait in every stage and for how long, without telling who gifts whom
+pub async fn user_get_pool_room_stages(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let rooms = get_rooms_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let changes = get_room_state_changes_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+
+    let now = Utc::now();
+    let mut stages = Vec::<RoomStageSummary>::new();
+    let room_states = (0..).map_while(|i| {RoomState::try_from(i).ok()});
+    for room_state in room_states {
+        let stage_rooms : Vec<_> = rooms.iter().filter(|r| {r.room_state() == room_state}).collect();
+        let mut durations = Vec::<i64>::new();
+        for room in &stage_rooms {
+            let entered_opt = changes.iter()
+                .filter(|c| {c.room_id() == room.id() && c.room_state() == room_state})
+                .map(|c| {c.creation_date()})
+                .max();
+            if entered_opt.is_some() {
+                durations.push((now - entered_opt.unwrap()).num_seconds());
+            }
+        }
+        let average_seconds = if durations.is_empty() { None } else { Some(durations.iter().sum::<i64>() / durations.len() as i64) };
+        stages.push(RoomStageSummary {
+            room_state: room_state,
+            rooms_count: stage_rooms.len(),
+            average_seconds: average_seconds,
+            longest_seconds: durations.iter().max().copied()
+        });
+    }
+
+    let result = UserPoolRoomStagesResponse {
+        pool_id: String::from(pool_id),
+        rooms_count: rooms.len(),
+        stages: stages
+    };
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+// only the mailer knows how the gift travels, the recipient sees the details in the room info
+pub async fn user_set_room_shipment(room_id : &str, account_id : &str, carrier_name : &str, tracking_number : &str, expected_delivery_date : Option<NaiveDate>, state : &AppState) -> ApiResponse {
+    let room_opt = get_room_by_id(room_id, state).await;
+    if room_opt.is_none() {
+        let err_msg = format!("Room with id \"{room_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let room = room_opt.unwrap();
+
+    let pool_id = room.pool_id();
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Started != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow change shipment details. Shipment details can be changed only when the pool is started");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    if room.mailer_id() != account_id {
+        let err_msg = format!("Only the mailer of room with id \"{room_id}\" can change its shipment details");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let carrier_name = carrier_name.trim();
+    let tracking_number = tracking_number.trim();
+    if carrier_name.is_empty() || tracking_number.is_empty() {
+        let err_msg = format!("Carrier name and tracking number can not be empty");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    if carrier_name.chars().count() > 256 || tracking_number.chars().count() > 128 {
+        let err_msg = format!("Carrier name can not be longer than 256 characters and tracking number can not be longer than 128 characters");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let shipment_exists = get_shipment_by_room_id(room_id, state).await.is_some();
+    if shipment_exists {
+        set_shipment_details_by_room_id(room_id, carrier_name, tracking_number, expected_delivery_date, Utc::now(), state).await;
+    } else {
+        let shipment_id = new_id_safe(is_shipment_already_exists_by_id, state).await;
+        create_shipment(shipment_id.as_str(), room_id, pool_id, carrier_name, tracking_number, expected_delivery_date, Utc::now(), state).await;
+    }
+    let msg = format!("Shipment details of room with id \"{room_id}\" were saved");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+// the address is bound to its pool and owner, see encrypt_address
+fn member_address_owner_id(pool_id : &str, account_id : &str) -> String {
+    return format!("{pool_id}/{account_id}");
+}
+
+async fn delete_member_address(pool_id : &str, account_id : &str, state : &AppState) -> () {
+    let member_address_opt = get_member_address_by_pool_and_account_ids(pool_id, account_id, state).await;
+    if member_address_opt.is_some() {
+        delete_member_address_by_id(member_address_opt.unwrap().id(), state).await;
+    }
+}
+
+async fn read_member_address(pool_id : &str, account_id : &str, state : &AppState) -> Option<DeliveryAddress> {
+    let member_address_opt = get_member_address_by_pool_and_account_ids(pool_id, account_id, state).await;
+    if member_address_opt.is_none() { return None; }
+    let member_address = member_address_opt.unwrap();
+
+    let config_secret = state.config.lock().await.server.config_secret.clone();
+    let owner_id = member_address_owner_id(pool_id, account_id);
+    return decrypt_address(member_address.encrypted_address(), owner_id.as_str(), config_secret.as_str());
+}
+
+pub async fn user_set_my_address_in_pool(pool_id : &str, account_id : &str, address : DeliveryAddress, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Ended == pool.state() || PoolState::Archived == pool.state() {
+        let err_msg = format!("Pool with id \"{pool_id}\" already ended, delivery addresses are not kept after the end");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let member_exists = is_member_already_exists_by_pool_and_account_ids(pool_id, account_id, state).await;
+    if !member_exists {
+        let err_msg = format!("Member with account id \"{account_id}\" and pool id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let address = DeliveryAddress {
+        full_name: String::from(address.full_name.trim()),
+        country: String::from(address.country.trim()),
+        city: String::from(address.city.trim()),
+        address_line: String::from(address.address_line.trim()),
+        postal_code: String::from(address.postal_code.trim()),
+        phone: String::from(address.phone.trim()),
+        is_pickup_point: address.is_pickup_point,
+        comment: String::from(address.comment.trim())
+    };
+    if address.full_name.is_empty() || address.country.is_empty() || address.city.is_empty() || address.address_line.is_empty() {
+        let err_msg = format!("Full name, country, city and address line (or pickup point) are required");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let fields = [&address.full_name, &address.country, &address.city, &address.address_line, &address.postal_code, &address.phone, &address.comment];
+    if fields.iter().any(|f| {f.chars().count() > 256}) {
+        let err_msg = format!("Address fields can not be longer than 256 characters");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let config_secret = state.config.lock().await.server.config_secret.clone();
+    let owner_id = member_address_owner_id(pool_id, account_id);
+    let encrypted_address_opt = encrypt_address(&address, owner_id.as_str(), config_secret.as_str());
+    if encrypted_address_opt.is_none() {
+        let err_msg = format!("Delivery address could not be encrypted, it was not saved");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let encrypted_address = encrypted_address_opt.unwrap();
+
+    let member_address_opt = get_member_address_by_pool_and_account_ids(pool_id, account_id, state).await;
+    if member_address_opt.is_some() {
+        set_member_address_by_id(member_address_opt.unwrap().id(), encrypted_address.as_str(), Utc::now(), state).await;
+    } else {
+        let member_address_id = new_id_safe(is_member_address_already_exists_by_id, state).await;
+        create_member_address(member_address_id.as_str(), pool_id, account_id, encrypted_address.as_str(), Utc::now(), state).await;
+    }
+    let msg = format!("Delivery address of member with account id \"{account_id}\" in pool with id \"{pool_id}\" was saved");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+pub async fn user_get_my_address_in_pool(pool_id : &str, account_id : &str, state : &AppState) -> ApiResponse {
+    let address_opt = read_member_address(pool_id, account_id, state).await;
+    if address_opt.is_none() {
+        let err_msg = format!("Member with account id \"{account_id}\" has no delivery address in pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(address_opt.unwrap()).unwrap());
+}
+
+pub async fn user_delete_my_address_in_pool(pool_id : &str, account_id : &str, state : &AppState) -> ApiResponse {
+    delete_member_address(pool_id, account_id, state).await;
+    let msg = format!("Delivery address of member with account id \"{account_id}\" in pool with id \"{pool_id}\" was deleted");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+// only the santa of the room gets the address and only after the draw
+pub async fn user_get_recipient_address(room_id : &str, account_id : &str, state : &AppState) -> ApiResponse {
+    let room_opt = get_room_by_id(room_id, state).await;
+    if room_opt.is_none() {
+        let err_msg = format!("Room with id \"{room_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let room = room_opt.unwrap();
+
+    let pool_id = room.pool_id();
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Started != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow read delivery addresses. Addresses are available only when the pool is started");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    if room.mailer_id() != account_id {
+        let err_msg = format!("Only the mailer of room with id \"{room_id}\" can read the delivery address of its recipient");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let address_opt = read_member_address(pool_id, room.recipient_id(), state).await;
+    if address_opt.is_none() {
+        let err_msg = format!("Recipient of room with id \"{room_id}\" has not shared a delivery address yet");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(address_opt.unwrap()).unwrap());
+}
+
+const THANK_YOU_PHOTO_TYPES : [&str; 4] = ["image/png", "image/jpeg", "image/webp", "image/gif"];
+const THANK_YOU_PHOTO_MAX_BYTES : usize = 1024 * 1024;
+const THANK_YOU_TEXT_MAX_LEN : usize = 2000;
+
+// photo comes as a data url, it is kept as its type and base64 payload
+fn parse_photo_data_url(photo : &str) -> Result<(String, String), String> {
+    let parts_opt = photo.strip_prefix("data:").and_then(|rest| {rest.split_once(";base64,")});
+    if parts_opt.is_none() {
+        return Err(String::from("Photo must be a base64 data url"));
+    }
+    let (photo_type, payload) = parts_opt.unwrap();
+    if !THANK_YOU_PHOTO_TYPES.contains(&photo_type) {
+        let types = THANK_YOU_PHOTO_TYPES.join(", ");
+        return Err(format!("Photo type must be one of {types}"));
+    }
+    let decoded = BASE64.decode(payload.as_bytes());
+    if decoded.is_err() {
+        return Err(String::from("Photo is not valid base64"));
+    }
+    if decoded.unwrap().len() > THANK_YOU_PHOTO_MAX_BYTES {
+        return Err(format!("Photo can not be larger than {THANK_YOU_PHOTO_MAX_BYTES} bytes"));
+    }
+    return Ok((String::from(photo_type), String::from(payload)));
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserThankYouNoteResponse {
+    pub room_id : String,
+    pub text_content : String,
+    pub photo : Option<String>,
+    pub creation_date : DateTime<Utc>
+}
+
+// no account id is kept with the note, it belongs to the room and is read by its mailer
+pub async fn user_set_thank_you_note(room_id : &str, account_id : &str, text_content : &str, photo : &str, state : &AppState) -> ApiResponse {
+    let room_opt = get_room_by_id(room_id, state).await;
+    if room_opt.is_none() {
+        let err_msg = format!("Room with id \"{room_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let room = room_opt.unwrap();
+
+    let pool_id = room.pool_id();
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Started != pool.state() && PoolState::Ended != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow thank-you notes");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    if room.recipient_id() != account_id {
+        let err_msg = format!("Only the recipient of room with id \"{room_id}\" can leave a thank-you note");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    if RoomState::RecipientTookTheGift != room.room_state() {
+        let err_msg = format!("Room with id \"{room_id}\" does not allow thank-you notes. Confirm that you took the gift first");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let text_content = text_content.trim();
+    if text_content.is_empty() {
+        let err_msg = format!("Thank-you note is empty");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    if text_content.chars().count() > THANK_YOU_TEXT_MAX_LEN {
+        let err_msg = format!("Thank-you note can not be longer than {THANK_YOU_TEXT_MAX_LEN} characters");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let (photo_type, photo_payload) = if photo.is_empty() {
+        (String::new(), String::new())
+    } else {
+        let photo_result = parse_photo_data_url(photo);
+        if photo_result.is_err() {
+            return ApiResponse::error_from_str(photo_result.err().unwrap().as_str());
+        }
+        photo_result.unwrap()
+    };
+
+    let note_opt = get_thank_you_note_by_room_id(room_id, state).await;
+    if note_opt.is_some() {
+        set_thank_you_note_by_id(note_opt.unwrap().id(), text_content, photo_type.as_str(), photo_payload.as_str(), Utc::now(), state).await;
+    } else {
+        let note_id = new_id_safe(is_thank_you_note_already_exists_by_id, state).await;
+        create_thank_you_note(note_id.as_str(), room_id, pool_id, text_content, photo_type.as_str(), photo_payload.as_str(), Utc::now(), state).await;
+    }
+    let msg = format!("Thank-you note in room with id \"{room_id}\" was saved");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+pub async fn user_get_thank_you_note(room_id : &str, state : &AppState) -> ApiResponse {
+    let note_opt = get_thank_you_note_by_room_id(room_id, state).await;
+    if note_opt.is_none() {
+        let err_msg = format!("Room with id \"{room_id}\" has no thank-you note");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let note = note_opt.unwrap();
+    let photo = if note.photo().is_empty() { None } else { Some(format!("data:{};base64,{}", note.photo_type(), note.photo())) };
+    let result = UserThankYouNoteResponse {
+        room_id: String::from(room_id),
+        text_content: String::from(note.text_content()),
+        photo: photo,
+        creation_date: note.creation_date()
+    };
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RoomReceiptSummary {
+    pub room_id : String,
+    pub recipient_nickname : String,
+    pub room_state : RoomState,
+    pub is_received : bool,
+    pub received_date : Option<DateTime<Utc>>,
+    pub has_thank_you_note : bool
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserPoolReceiptsResponse {
+    pub pool_id : String,
+    pub rooms_count : usize,
+    pub received_rooms_count : usize,
+    pub thanked_rooms_count : usize,
+    pub rooms : Vec<RoomReceiptSummary>
+}
+
+// mailers are left out, the owner sees only who got their gift
+pub async fn user_get_pool_receipts(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let rooms = get_rooms_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let changes = get_room_state_changes_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let notes = get_thank_you_notes_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+
+    let mut result_rooms = Vec::<RoomReceiptSummary>::new();
+    for room in &rooms {
+        let is_received = RoomState::RecipientTookTheGift == room.room_state();
+        let received_date = if is_received {
+            changes.iter()
+                .filter(|c| {c.room_id() == room.id() && RoomState::RecipientTookTheGift == c.room_state()})
+                .map(|c| {c.creation_date()})
+                .max()
+        } else {
+            None
+        };
+        result_rooms.push(RoomReceiptSummary {
+            room_id: String::from(room.id()),
+            recipient_nickname: get_nickname_or_account_id(room.recipient_id(), state).await,
+            room_state: room.room_state(),
+            is_received: is_received,
+            received_date: received_date,
+            has_thank_you_note: notes.iter().any(|n| {n.room_id() == room.id()})
+        });
+    }
+
+    let result = UserPoolReceiptsResponse {
+        pool_id: String::from(pool_id),
+        rooms_count: result_rooms.len(),
+        received_rooms_count: result_rooms.iter().filter(|r| {r.is_received}).count(),
+        thanked_rooms_count: result_rooms.iter().filter(|r| {r.has_thank_you_note}).count(),
+        rooms: result_rooms
+    };
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserStageDeadline {
+    pub room_state : RoomState,
+    pub deadline : DateTime<Utc>
+}
+
+// a deadline of a stage is the date every room has to reach that stage by,
+// the missed one is the earliest stage the room has not reached in time
+fn find_missed_deadline<D : IStageDeadline>(room_state : RoomState, deadlines : &Vec<D>, now : DateTime<Utc>) -> Option<&D> {
+    let room_state_num = room_state as usize;
+    return deadlines.iter()
+        .filter(|d| {(d.room_state() as usize) > room_state_num && d.deadline() < now})
+        .min_by_key(|d| {d.room_state() as usize});
+}
+
+async fn is_room_overdue(pool_id : &str, pool_state : PoolState, room_state : RoomState, state : &AppState) -> bool {
+    if PoolState::Started != pool_state { return false; }
+    let deadlines = get_stage_deadlines_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    return find_missed_deadline(room_state, &deadlines, Utc::now()).is_some();
+}
+
+pub async fn user_set_pool_stage_deadlines(pool_id : &str, deadlines : Vec<UserStageDeadline>, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Ended == pool.state() || PoolState::Archived == pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow changing stage deadlines. The pool already ended");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let mut sorted_deadlines = deadlines.clone();
+    sorted_deadlines.sort_by_key(|d| {d.room_state.clone() as usize});
+    for (i, stage_deadline) in sorted_deadlines.iter().enumerate() {
+        let room_state = stage_deadline.room_state.clone();
+        if RoomState::ChoosingAGift == room_state {
+            let err_msg = format!("Every room starts in {room_state:?}, it can not have a deadline");
+            return ApiResponse::error_from_str(err_msg.as_str());
+        }
+        if i == 0 { continue; }
+        let previous = &sorted_deadlines[i - 1];
+        if previous.room_state == room_state {
+            let err_msg = format!("Stage {room_state:?} has more than one deadline");
+            return ApiResponse::error_from_str(err_msg.as_str());
+        }
+        if previous.deadline > stage_deadline.deadline {
+            let previous_room_state = previous.room_state.clone();
+            let err_msg = format!("Deadline of stage {room_state:?} is earlier than deadline of the previous stage {previous_room_state:?}");
+            return ApiResponse::error_from_str(err_msg.as_str());
+        }
+    }
+
+    delete_stage_deadlines_by_pool_id(pool_id, state).await;
+    for stage_deadline in sorted_deadlines {
+        let stage_deadline_id = new_id_safe(is_stage_deadline_already_exists_by_id, state).await;
+        create_stage_deadline(stage_deadline_id.as_str(), pool_id, stage_deadline.room_state, stage_deadline.deadline, state).await;
+    }
+    let msg = format!("Stage deadlines of pool with id \"{pool_id}\" were saved");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+pub async fn user_get_pool_stage_deadlines(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_exists = is_pool_already_exists_by_id(pool_id, state).await;
+    if pool_exists.is_none_or(|b| {!b}) {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let deadlines = get_stage_deadlines_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let mut result : Vec<UserStageDeadline> = deadlines.iter().map(|d| {UserStageDeadline {
+        room_state: d.room_state(),
+        deadline: d.deadline()
+    }}).collect();
+    result.sort_by_key(|d| {d.room_state.clone() as usize});
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct OverdueRoom {
+    pub room_id : String,
+    pub room_state : RoomState,
+    pub missed_room_state : RoomState,
+    pub deadline : DateTime<Utc>,
+    pub overdue_seconds : i64,
+    pub waiting_for_account_id : String,
+    pub waiting_for_nickname : String
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserOverdueRoomsResponse {
+    pub pool_id : String,
+    pub rooms_count : usize,
+    pub overdue_rooms : Vec<OverdueRoom>
+}
+
+// the owner chases whoever has to make the next step of a late room, the other side stays hidden
+pub async fn user_get_overdue_rooms(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+
+    let rooms = get_rooms_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let deadlines = get_stage_deadlines_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+
+    let now = Utc::now();
+    let mut overdue_rooms = Vec::<OverdueRoom>::new();
+    if PoolState::Started == pool.state() {
+        for room in &rooms {
+            let missed_deadline_opt = find_missed_deadline(room.room_state(), &deadlines, now);
+            if missed_deadline_opt.is_none() { continue; }
+            let missed_deadline = missed_deadline_opt.unwrap();
+
+            let next_room_state_num = room.room_state() as usize + 1;
+            let is_recipient_step = next_room_state_num >= RoomState::GiftHasBeenDeliveredToRecipient as usize;
+            let waiting_for_account_id = if is_recipient_step { room.recipient_id() } else { room.mailer_id() };
+            overdue_rooms.push(OverdueRoom {
+                room_id: String::from(room.id()),
+                room_state: room.room_state(),
+                missed_room_state: missed_deadline.room_state(),
+                deadline: missed_deadline.deadline(),
+                overdue_seconds: (now - missed_deadline.deadline()).num_seconds(),
+                waiting_for_account_id: String::from(waiting_for_account_id),
+                waiting_for_nickname: get_nickname_or_account_id(waiting_for_account_id, state).await
+            });
+        }
+    }
+    overdue_rooms.sort_by_key(|r| {-r.overdue_seconds});
+
+    let result = UserOverdueRoomsResponse {
+        pool_id: String::from(pool_id),
+        rooms_count: rooms.len(),
+        overdue_rooms: overdue_rooms
+    };
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+// public pools are listed for everyone, unlisted ones are open to whoever has the pool id,
+// private ones only to the owner, members and people coming with a join token
+pub async fn is_pool_visible_to_account(pool_id : &str, account_id : &str, state : &AppState) -> bool {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() { return false; }
+    let pool = pool_opt.unwrap();
+    if PoolVisibility::Private != pool.visibility() { return true; }
+    if is_pool_organizer(pool_id, account_id, state).await { return true; }
+    if get_pool_invitation_by_pool_and_account_ids(pool_id, account_id, state).await.is_some() { return true; }
+    return is_member_already_exists_by_pool_and_account_ids(pool_id, account_id, state).await;
+}
+
+pub async fn user_set_pool_visibility(pool_id : &str, visibility : PoolVisibility, state : &AppState) -> ApiResponse {
+    let pool_exists = is_pool_already_exists_by_id(pool_id, state).await;
+    if pool_exists.is_none_or(|b| {!b}) {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    set_pool_visibility_by_id(pool_id, visibility.clone(), state).await;
+    let msg = format!("Pool with id \"{pool_id}\" is now {visibility:?}");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserPoolJoinTokenResponse {
+    pub id : String,
+    pub token : String,
+    pub max_uses : u64,
+    pub uses_count : u64,
+    pub is_revoked : bool,
+    pub is_active : bool,
+    pub creation_date : DateTime<Utc>
+}
+
+fn is_pool_join_token_active(join_token : &impl IPoolJoinToken) -> bool {
+    if join_token.is_revoked() { return false; }
+    return join_token.max_uses() == 0 || join_token.uses_count() < join_token.max_uses();
+}
+
+fn pool_join_token_to_response(join_token : &impl IPoolJoinToken) -> UserPoolJoinTokenResponse {
+    return UserPoolJoinTokenResponse {
+        id: String::from(join_token.id()),
+        token: String::from(join_token.token()),
+        max_uses: join_token.max_uses(),
+        uses_count: join_token.uses_count(),
+        is_revoked: join_token.is_revoked(),
+        is_active: is_pool_join_token_active(join_token),
+        creation_date: join_token.creation_date()
+    };
+}
+
+pub async fn user_create_pool_join_token(pool_id : &str, max_uses : u64, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Created != pool.state() && PoolState::Open != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow creating join links. New members can join only before the draw");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let mut token = generate_random_token();
+    while is_pool_join_token_already_exists_by_token(token.as_str(), state).await.is_none_or(|b| {b}) {
+        token = generate_random_token();
+    }
+    let join_token_id = new_id_safe(is_pool_join_token_already_exists_by_id, state).await;
+    create_pool_join_token(join_token_id.as_str(), pool_id, token.as_str(), max_uses, Utc::now(), state).await;
+
+    let join_token_opt = get_pool_join_token_by_id(join_token_id.as_str(), state).await;
+    if join_token_opt.is_none() {
+        let err_msg = format!("Join link for pool with id \"{pool_id}\" was not saved");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let result = pool_join_token_to_response(&join_token_opt.unwrap());
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+pub async fn user_get_pool_join_tokens(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_exists = is_pool_already_exists_by_id(pool_id, state).await;
+    if pool_exists.is_none_or(|b| {!b}) {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let join_tokens = get_pool_join_tokens_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let mut result : Vec<UserPoolJoinTokenResponse> = join_tokens.iter().map(|t| {pool_join_token_to_response(t)}).collect();
+    result.sort_by_key(|t| {t.creation_date});
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+pub async fn user_revoke_pool_join_token(pool_id : &str, join_token_id : &str, state : &AppState) -> ApiResponse {
+    let join_token_opt = get_pool_join_token_by_id(join_token_id, state).await;
+    if join_token_opt.is_none_or(|t| {t.pool_id() != pool_id}) {
+        let err_msg = format!("Join link with id \"{join_token_id}\" not found in pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    revoke_pool_join_token_by_id(join_token_id, state).await;
+    let msg = format!("Join link with id \"{join_token_id}\" was revoked");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserJoinPoolPreviewResponse {
+    pub pool_id : String,
+    pub name : String,
+    pub description : String,
+    pub min_price : u64,
+    pub max_price : u64,
+    pub pool_state : PoolState,
+    pub members_count : usize,
+    pub questions : Vec<UserPoolQuestionResponse>
+}
+
+// an unknown, revoked and used up token look the same, so tokens can not be probed
+pub async fn user_get_pool_by_join_token(token : &str, state : &AppState) -> ApiResponse {
+    let join_token_opt = get_pool_join_token_by_token(token, state).await;
+    if join_token_opt.as_ref().is_none_or(|t| {!is_pool_join_token_active(t)}) {
+        return ApiResponse::error_from_str("Join link is not valid");
+    }
+    let join_token = join_token_opt.unwrap();
+    let pool_id = join_token.pool_id();
+
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    let members = get_members_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+
+    let result = UserJoinPoolPreviewResponse {
+        pool_id: String::from(pool_id),
+        name: String::from(pool.name()),
+        description: String::from(pool.description()),
+        min_price: pool.min_price(),
+        max_price: pool.max_price(),
+        pool_state: pool.state(),
+        members_count: members.len(),
+        questions: get_pool_questions_response(pool_id, state).await
+    };
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+pub async fn user_join_pool_by_token(token : &str, account_id : &str, wishlist : &str, answers : &Vec<UserQuestionAnswer>, state : &AppState) -> ApiResponse {
+    let join_token_opt = get_pool_join_token_by_token(token, state).await;
+    if join_token_opt.as_ref().is_none_or(|t| {!is_pool_join_token_active(t)}) {
+        return ApiResponse::error_from_str("Join link is not valid");
+    }
+    let join_token = join_token_opt.unwrap();
+    if !take_pool_join_token_use_by_id(join_token.id(), state).await {
+        return ApiResponse::error_from_str("Join link is not valid");
+    }
+
+    // a use is spent only by a real join, a failed join or a waitlist place gives it back
+    let pool_id = join_token.pool_id();
+    let resp = user_add_member_to_pool(account_id, pool_id, wishlist, answers, true, state).await;
+    if !resp.is_ok() || !is_member_already_exists_by_pool_and_account_ids(pool_id, account_id, state).await {
+        return_pool_join_token_use_by_id(join_token.id(), state).await;
+    }
+    return resp;
+}
+
+// free places of an open pool go to the earliest waitlist entries
+async fn promote_from_waitlist(pool_id : &str, state : &AppState) -> () {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() { return; }
+    let pool = pool_opt.unwrap();
+    if PoolState::Open != pool.state() { return; }
+
+    let members = get_members_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let mut members_count = members.len() as u64;
+    let waitlist_entries = get_waitlist_entries_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    for waitlist_entry in waitlist_entries {
+        if pool.max_members() > 0 && members_count >= pool.max_members() { break; }
+        let account_id = waitlist_entry.account_id();
+        if !is_member_already_exists_by_pool_and_account_ids(pool_id, account_id, state).await {
+            let member_id = new_id_safe(is_member_already_exists_by_id, state).await;
+            create_member(member_id.as_str(), account_id, pool_id, waitlist_entry.wishlist(), "", state).await;
+            members_count += 1;
+            tracing::info!("User with account id \"{account_id}\" was moved from the waitlist to pool with id \"{pool_id}\"");
+        }
+        delete_waitlist_entry_by_id(waitlist_entry.id(), state).await;
+    }
+}
+
+pub async fn user_set_pool_max_members(pool_id : &str, max_members : u64, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Created != pool.state() && PoolState::Open != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow changing the member limit. The limit can be changed only before the draw");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    // members above a lowered limit stay, only new users go to the waitlist
+    set_pool_max_members_by_id(pool_id, max_members, state).await;
+    promote_from_waitlist(pool_id, state).await;
+    let msg = format!("Member limit of pool with id \"{pool_id}\" was set to {max_members}");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserWaitlistEntryResponse {
+    pub account_id : String,
+    pub nickname : String,
+    pub position : usize,
+    pub creation_date : DateTime<Utc>
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserPoolWaitlistResponse {
+    pub pool_id : String,
+    pub max_members : u64,
+    pub members_count : usize,
+    pub entries : Vec<UserWaitlistEntryResponse>
+}
+
+pub async fn user_get_pool_waitlist(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+
+    let members = get_members_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let waitlist_entries = get_waitlist_entries_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let mut entries = Vec::<UserWaitlistEntryResponse>::new();
+    for (i, waitlist_entry) in waitlist_entries.iter().enumerate() {
+        entries.push(UserWaitlistEntryResponse {
+            account_id: String::from(waitlist_entry.account_id()),
+            nickname: get_nickname_or_account_id(waitlist_entry.account_id(), state).await,
+            position: i + 1,
+            creation_date: waitlist_entry.creation_date()
+        });
+    }
+
+    let result = UserPoolWaitlistResponse {
+        pool_id: String::from(pool_id),
+        max_members: pool.max_members(),
+        members_count: members.len(),
+        entries: entries
+    };
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserWaitlistPositionResponse {
+    pub pool_id : String,
+    pub position : usize,
+    pub waitlist_length : usize
+}
+
+pub async fn user_get_my_waitlist_position(pool_id : &str, account_id : &str, state : &AppState) -> ApiResponse {
+    let waitlist_entries = get_waitlist_entries_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let position_opt = waitlist_entries.iter().position(|e| {e.account_id() == account_id});
+    if position_opt.is_none() {
+        let err_msg = format!("User with account id \"{account_id}\" is not on the waitlist of pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let result = UserWaitlistPositionResponse {
+        pool_id: String::from(pool_id),
+        position: position_opt.unwrap() + 1,
+        waitlist_length: waitlist_entries.len()
+    };
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+pub async fn user_delete_from_waitlist(pool_id : &str, account_id : &str, state : &AppState) -> ApiResponse {
+    let waitlist_entry_opt = get_waitlist_entry_by_pool_and_account_ids(pool_id, account_id, state).await;
+    if waitlist_entry_opt.is_none() {
+        let err_msg = format!("User with account id \"{account_id}\" is not on the waitlist of pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    delete_waitlist_entry_by_id(waitlist_entry_opt.unwrap().id(), state).await;
+    if !is_member_already_exists_by_pool_and_account_ids(pool_id, account_id, state).await {
+        delete_member_answers_by_pool_and_account_ids(pool_id, account_id, state).await;
+    }
+    let msg = format!("User with account id \"{account_id}\" was removed from the waitlist of pool with id \"{pool_id}\"");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+// the organizer may let someone in out of turn, even when it takes the pool over its limit
+pub async fn user_promote_from_waitlist(pool_id : &str, account_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Open != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow add new members. Members addition is available only at the open stage");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let waitlist_entry_opt = get_waitlist_entry_by_pool_and_account_ids(pool_id, account_id, state).await;
+    if waitlist_entry_opt.is_none() {
+        let err_msg = format!("User with account id \"{account_id}\" is not on the waitlist of pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let waitlist_entry = waitlist_entry_opt.unwrap();
+
+    let member_id = new_id_safe(is_member_already_exists_by_id, state).await;
+    if !is_member_already_exists_by_pool_and_account_ids(pool_id, account_id, state).await {
+        create_member(member_id.as_str(), account_id, pool_id, waitlist_entry.wishlist(), "", state).await;
+    }
+    delete_waitlist_entry_by_id(waitlist_entry.id(), state).await;
+    let msg = format!("User with account id \"{account_id}\" was moved from the waitlist to pool with id \"{pool_id}\"");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+// the clone starts from scratch: no schedule, no members, no answers to the copied questions, previous members only get invitations
+pub async fn user_clone_pool(pool_id : &str, name : &str, description : Option<String>, invite_members : bool, link_history : bool, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+
+    let description = description.unwrap_or(String::from(pool.description()));
+    let previous_pool_id = if link_history { pool_id } else { "" };
+    let resp = user_create_pool(name, description.as_str(), pool.account_id(), pool.min_price(), pool.max_price(), previous_pool_id, pool.history_depth(), pool.gifts_per_member(), pool.cross_group_draw(), pool.end_room_state(), None, None, None, pool.visibility(), pool.max_members(), pool.currency(), state).await;
+    if !resp.is_ok() { return resp; }
+    let new_pool_id = String::from(resp.body.as_str().unwrap_or_default());
+
+    let questions = get_pool_questions_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    for question in questions.iter() {
+        let question_id = new_id_safe(is_pool_question_already_exists_by_id, state).await;
+        create_pool_question(question_id.as_str(), new_pool_id.as_str(), question.text_content(), question.is_required(), question.position(), state).await;
+    }
+
+    if invite_members {
+        let members = get_members_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+        for member in members.iter() {
+            let invitation_id = new_id_safe(is_pool_invitation_already_exists_by_id, state).await;
+            create_pool_invitation(invitation_id.as_str(), new_pool_id.as_str(), member.account_id(), Utc::now(), state).await;
+        }
+    }
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(new_pool_id).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserPoolInvitationResponse {
+    pub id : String,
+    pub pool_id : String,
+    pub pool_name : String,
+    pub pool_state : PoolState,
+    pub account_id : String,
+    pub nickname : String,
+    pub creation_date : DateTime<Utc>
+}
+
+async fn pool_invitation_to_response<P: IPoolInvitation>(invitation : &P, state : &AppState) -> Option<UserPoolInvitationResponse> {
+    let pool_opt = get_pool_by_id(invitation.pool_id(), state).await;
+    if pool_opt.is_none() { return None; }
+    let pool = pool_opt.unwrap();
+
+    return Some(UserPoolInvitationResponse {
+        id: String::from(invitation.id()),
+        pool_id: String::from(invitation.pool_id()),
+        pool_name: String::from(pool.name()),
+        pool_state: pool.state(),
+        account_id: String::from(invitation.account_id()),
+        nickname: get_nickname_or_account_id(invitation.account_id(), state).await,
+        creation_date: invitation.creation_date()
+    });
+}
+
+pub async fn user_get_pool_invitations(pool_id : &str, state : &AppState) -> ApiResponse {
+    let invitations = get_pool_invitations_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let mut result = Vec::<UserPoolInvitationResponse>::new();
+    for invitation in invitations.iter() {
+        let invitation_resp_opt = pool_invitation_to_response(invitation, state).await;
+        if invitation_resp_opt.is_some() { result.push(invitation_resp_opt.unwrap()); }
+    }
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+pub async fn user_get_my_pool_invitations(account_id : &str, state : &AppState) -> ApiResponse {
+    let invitations = get_pool_invitations_by_account_id(account_id, state).await.unwrap_or(vec![]);
+    let mut result = Vec::<UserPoolInvitationResponse>::new();
+    for invitation in invitations.iter() {
+        let invitation_resp_opt = pool_invitation_to_response(invitation, state).await;
+        if invitation_resp_opt.is_some() { result.push(invitation_resp_opt.unwrap()); }
+    }
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+// the invitation is kept until the pool opens, so it can be accepted later
+pub async fn user_accept_pool_invitation(invitation_id : &str, account_id : &str, wishlist : &str, answers : &Vec<UserQuestionAnswer>, state : &AppState) -> ApiResponse {
+    let invitation_opt = get_pool_invitation_by_id(invitation_id, state).await;
+    if invitation_opt.is_none() || invitation_opt.as_ref().unwrap().account_id() != account_id {
+        let err_msg = format!("Invitation with id \"{invitation_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let invitation = invitation_opt.unwrap();
+
+    let resp = user_add_member_to_pool(account_id, invitation.pool_id(), wishlist, answers, true, state).await;
+    if resp.is_ok() {
+        delete_pool_invitation_by_id(invitation_id, state).await;
+    }
+    return resp;
+}
+
+pub async fn user_decline_pool_invitation(invitation_id : &str, account_id : &str, state : &AppState) -> ApiResponse {
+    let invitation_opt = get_pool_invitation_by_id(invitation_id, state).await;
+    if invitation_opt.is_none() || invitation_opt.unwrap().account_id() != account_id {
+        let err_msg = format!("Invitation with id \"{invitation_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    delete_pool_invitation_by_id(invitation_id, state).await;
+    let msg = format!("Invitation with id \"{invitation_id}\" was declined");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+// co-organizers manage the pool like its owner, only the owner can hand it over or delete it
+pub async fn is_pool_organizer(pool_id : &str, account_id : &str, state : &AppState) -> bool {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() { return false; }
+    if pool_opt.unwrap().account_id() == account_id { return true; }
+    return get_pool_organizer_by_pool_and_account_ids(pool_id, account_id, state).await.is_some();
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserPoolOrganizerResponse {
+    pub account_id : String,
+    pub nickname : String,
+    pub is_owner : bool
+}
+
+pub async fn user_get_pool_organizers(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+
+    let mut result = vec![UserPoolOrganizerResponse {
+        account_id: String::from(pool.account_id()),
+        nickname: get_nickname_or_account_id(pool.account_id(), state).await,
+        is_owner: true
+    }];
+    let organizers = get_pool_organizers_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    for organizer in organizers.iter() {
+        result.push(UserPoolOrganizerResponse {
+            account_id: String::from(organizer.account_id()),
+            nickname: get_nickname_or_account_id(organizer.account_id(), state).await,
+            is_owner: false
+        });
+    }
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+pub async fn user_add_pool_organizer(pool_id : &str, account_id : &str, state : &AppState) -> ApiResponse {
+    let pool_exists = is_pool_already_exists_by_id(pool_id, state).await;
+    if pool_exists.is_none_or(|b| {!b}) {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let account_exists = is_account_already_exists_by_id(account_id, state).await;
+    if account_exists.is_none_or(|b| {!b}) {
+        let err_msg = format!("Account with id \"{account_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    if is_pool_organizer(pool_id, account_id, state).await {
+        let err_msg = format!("User with account id \"{account_id}\" already organizes pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let organizer_id = new_id_safe(is_pool_organizer_already_exists_by_id, state).await;
+    create_pool_organizer(organizer_id.as_str(), pool_id, account_id, Utc::now(), state).await;
+    let msg = format!("User with account id \"{account_id}\" was added as an organizer of pool with id \"{pool_id}\"");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+pub async fn user_delete_pool_organizer(pool_id : &str, account_id : &str, state : &AppState) -> ApiResponse {
+    let organizer_opt = get_pool_organizer_by_pool_and_account_ids(pool_id, account_id, state).await;
+    if organizer_opt.is_none() {
+        let err_msg = format!("User with account id \"{account_id}\" is not a co-organizer of pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    delete_pool_organizer_by_id(organizer_opt.unwrap().id(), state).await;
+    let msg = format!("User with account id \"{account_id}\" is no longer an organizer of pool with id \"{pool_id}\"");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+pub async fn user_transfer_pool_ownership(pool_id : &str, account_id : &str, keep_as_organizer : bool, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    let previous_owner_id = pool.account_id();
+    if previous_owner_id == account_id {
+        let err_msg = format!("User with account id \"{account_id}\" already owns pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let account_exists = is_account_already_exists_by_id(account_id, state).await;
+    if account_exists.is_none_or(|b| {!b}) {
+        let err_msg = format!("Account with id \"{account_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    // the new owner does not need a co-organizer entry anymore
+    let organizer_opt = get_pool_organizer_by_pool_and_account_ids(pool_id, account_id, state).await;
+    if organizer_opt.is_some() {
+        delete_pool_organizer_by_id(organizer_opt.unwrap().id(), state).await;
+    }
+    set_pool_owner_by_id(pool_id, account_id, state).await;
+    if keep_as_organizer {
+        let organizer_id = new_id_safe(is_pool_organizer_already_exists_by_id, state).await;
+        create_pool_organizer(organizer_id.as_str(), pool_id, previous_owner_id, Utc::now(), state).await;
+    }
+    let msg = format!("Pool with id \"{pool_id}\" now belongs to user with account id \"{account_id}\"");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+// the recipient is not told how much was spent on the gift
+pub async fn user_set_gift_spend(room_id : &str, account_id : &str, amount : u64, currency : Option<String>, state : &AppState) -> ApiResponse {
+    let room_opt = get_room_by_id(room_id, state).await;
+    if room_opt.is_none() {
+        let err_msg = format!("Room with id \"{room_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let room = room_opt.unwrap();
+
+    let pool_id = room.pool_id();
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+    if PoolState::Started != pool.state() {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow recording gift spends. Gift spends can be recorded only when the pool is started");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    if room.mailer_id() != account_id {
+        let err_msg = format!("Only the mailer of room with id \"{room_id}\" can record its gift spend");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let pool_currency = pool.currency();
+    let currency = normalize_currency_code(currency.unwrap_or(String::from(pool_currency)).as_str());
+    if currency != pool_currency {
+        let err_msg = format!("Gift spend must be recorded in the pool currency {pool_currency}");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let min_price = pool.min_price();
+    let max_price = pool.max_price();
+    if amount < min_price || amount > max_price {
+        let err_msg = format!("Gift spend {amount} {currency} is out of the pool price range {min_price}-{max_price} {currency}");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let gift_spend_opt = get_gift_spend_by_room_id(room_id, state).await;
+    if gift_spend_opt.is_some() {
+        set_gift_spend_by_id(gift_spend_opt.unwrap().id(), amount, currency.as_str(), Utc::now(), state).await;
+    } else {
+        let gift_spend_id = new_id_safe(is_gift_spend_already_exists_by_id, state).await;
+        create_gift_spend(gift_spend_id.as_str(), room_id, pool_id, amount, currency.as_str(), Utc::now(), state).await;
+    }
+    let msg = format!("Gift spend of room with id \"{room_id}\" was set to {amount} {currency}");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserGiftSpendResponse {
+    pub room_id : String,
+    pub mailer_id : String,
+    pub mailer_nickname : String,
+    pub amount : u64,
+    pub currency : String,
+    pub is_within_range : bool,
+    pub creation_date : DateTime<Utc>
+}
+
+pub async fn user_get_gift_spend(room_id : &str, account_id : &str, state : &AppState) -> ApiResponse {
+    let room_opt = get_room_by_id(room_id, state).await;
+    if room_opt.is_none() {
+        let err_msg = format!("Room with id \"{room_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let room = room_opt.unwrap();
+    if room.mailer_id() != account_id && !is_pool_organizer(room.pool_id(), account_id, state).await {
+        let err_msg = format!("Only the mailer of room with id \"{room_id}\" and pool organizers can see its gift spend");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool_opt = get_pool_by_id(room.pool_id(), state).await;
+    let gift_spend_opt = get_gift_spend_by_room_id(room_id, state).await;
+    if pool_opt.is_none() || gift_spend_opt.is_none() {
+        let err_msg = format!("Gift spend of room with id \"{room_id}\" is not recorded");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let result = gift_spend_to_response(&gift_spend_opt.unwrap(), room.mailer_id(), &pool_opt.unwrap(), state).await;
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+// the pool range may be changed after the spend is recorded, so the check is made on every read
+async fn gift_spend_to_response<G: IGiftSpend, P: IPool>(gift_spend : &G, mailer_id : &str, pool : &P, state : &AppState) -> UserGiftSpendResponse {
+    let amount = gift_spend.amount();
+    let is_within_range = gift_spend.currency() == pool.currency() && amount >= pool.min_price() && amount <= pool.max_price();
+    return UserGiftSpendResponse {
+        room_id: String::from(gift_spend.room_id()),
+        mailer_id: String::from(mailer_id),
+        mailer_nickname: get_nickname_or_account_id(mailer_id, state).await,
+        amount: amount,
+        currency: String::from(gift_spend.currency()),
+        is_within_range: is_within_range,
+        creation_date: gift_spend.creation_date()
+    };
+}
+
+pub async fn user_get_pool_gift_spends(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+
+    let rooms = get_rooms_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let gift_spends = get_gift_spends_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let mut result = Vec::<UserGiftSpendResponse>::new();
+    for gift_spend in gift_spends.iter() {
+        let room_opt = rooms.iter().find(|r| {r.id() == gift_spend.room_id()});
+        if room_opt.is_none() { continue; }
+        result.push(gift_spend_to_response(gift_spend, room_opt.unwrap().mailer_id(), &pool, state).await);
+    }
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserRoomStateCountResponse {
+    pub room_state : RoomState,
+    pub count : u64
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserDailyMessageCountResponse {
+    pub day : String,
+    pub count : u64
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserPoolStatsResponse {
+    pub pool_id : String,
+    pub pool_state : PoolState,
+    pub member_count : u64,
+    pub empty_wishlist_count : u64,
+    pub rooms_per_state : Vec<UserRoomStateCountResponse>,
+    pub messages_per_day : Vec<UserDailyMessageCountResponse>,
+    pub state_change_date : DateTime<Utc>,
+    pub seconds_since_state_change : i64
+}
+
+pub async fn user_get_pool_stats(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool = pool_opt.unwrap();
+
+    let (member_count, empty_wishlist_count) = count_members_by_pool_id(pool_id, state).await;
+    let room_state_counts = count_rooms_by_pool_id_per_state(pool_id, state).await.unwrap_or(vec![]);
+    let daily_message_counts = count_messages_by_pool_id_per_day(pool_id, state).await.unwrap_or(vec![]);
+
+    // every room state is listed, so the states without rooms have zero count
+    let rooms_per_state = (0..).map_while(|n| {RoomState::try_from(n).ok()}).map(|room_state| {
+        let count = room_state_counts.iter().find(|(s, _)| {*s == room_state}).map(|(_, c)| {*c}).unwrap_or(0);
+        UserRoomStateCountResponse { room_state: room_state, count: count }
+    }).collect();
+    let messages_per_day = daily_message_counts.into_iter().map(|(day, count)| {
+        UserDailyMessageCountResponse { day: day, count: count }
+    }).collect();
+
+    let result = UserPoolStatsResponse {
+        pool_id: String::from(pool_id),
+        pool_state: pool.state(),
+        member_count: member_count,
+        empty_wishlist_count: empty_wishlist_count,
+        rooms_per_state: rooms_per_state,
+        messages_per_day: messages_per_day,
+        state_change_date: pool.state_change_date(),
+        seconds_since_state_change: (Utc::now() - pool.state_change_date()).num_seconds()
+    };
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+const POOL_ANNOUNCEMENT_TEXT_MAX_LEN : usize = 4000;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserPoolAnnouncementResponse {
+    pub id : String,
+    pub pool_id : String,
+    pub author_id : String,
+    pub author_nickname : String,
+    pub text_content : String,
+    pub is_pinned : bool,
+    pub creation_date : DateTime<Utc>,
+    pub last_update_date : DateTime<Utc>
+}
+
+async fn pool_announcement_to_response<A: IPoolAnnouncement>(announcement : &A, state : &AppState) -> UserPoolAnnouncementResponse {
+    return UserPoolAnnouncementResponse {
+        id: String::from(announcement.id()),
+        pool_id: String::from(announcement.pool_id()),
+        author_id: String::from(announcement.account_id()),
+        author_nickname: get_nickname_or_account_id(announcement.account_id(), state).await,
+        text_content: String::from(announcement.text_content()),
+        is_pinned: announcement.is_pinned(),
+        creation_date: announcement.creation_date(),
+        last_update_date: announcement.last_update_date()
+    };
+}
+
+fn pool_announcement_text_error(text_content : &str) -> Option<String> {
+    if text_content.is_empty() {
+        return Some(format!("Announcement is empty"));
+    }
+    if text_content.chars().count() > POOL_ANNOUNCEMENT_TEXT_MAX_LEN {
+        return Some(format!("Announcement can not be longer than {POOL_ANNOUNCEMENT_TEXT_MAX_LEN} characters"));
+    }
+    return None;
+}
+
+// pinned announcements go first, the rest are ordered from newest to oldest
+pub async fn user_get_pool_announcements(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_exists = is_pool_already_exists_by_id(pool_id, state).await;
+    if pool_exists.is_none_or(|b| {!b}) {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let mut announcements = get_pool_announcements_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    announcements.sort_by(|a, b| {b.is_pinned().cmp(&a.is_pinned()).then(b.creation_date().cmp(&a.creation_date()))});
+    let mut result = Vec::<UserPoolAnnouncementResponse>::new();
+    for announcement in announcements.iter() {
+        result.push(pool_announcement_to_response(announcement, state).await);
+    }
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+pub async fn user_create_pool_announcement(pool_id : &str, account_id : &str, text_content : &str, is_pinned : bool, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    if PoolState::Archived == pool_opt.unwrap().state() {
+        let err_msg = format!("Pool with id \"{pool_id}\" is archived");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let text_content = text_content.trim();
+    let text_error = pool_announcement_text_error(text_content);
+    if text_error.is_some() {
+        return ApiResponse::error_from_str(text_error.unwrap().as_str());
+    }
+
+    let announcement_id = new_id_safe(is_pool_announcement_already_exists_by_id, state).await;
+    create_pool_announcement(announcement_id.as_str(), pool_id, account_id, text_content, is_pinned, Utc::now(), state).await;
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(announcement_id).unwrap());
+}
+
+pub async fn user_update_pool_announcement(pool_id : &str, announcement_id : &str, text_content : Option<String>, is_pinned : Option<bool>, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    if PoolState::Archived == pool_opt.unwrap().state() {
+        let err_msg = format!("Pool with id \"{pool_id}\" is archived");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let announcement_opt = get_pool_announcement_by_id(announcement_id, state).await;
+    if announcement_opt.is_none_or(|a| {a.pool_id() != pool_id}) {
+        let err_msg = format!("Announcement with id \"{announcement_id}\" not found in pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    if text_content.is_none() && is_pinned.is_none() {
+        let err_msg = format!("Nothing to update in announcement with id \"{announcement_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    if text_content.is_some() {
+        let text_content_string = text_content.unwrap();
+        let text_content = text_content_string.trim();
+        let text_error = pool_announcement_text_error(text_content);
+        if text_error.is_some() {
+            return ApiResponse::error_from_str(text_error.unwrap().as_str());
+        }
+        set_pool_announcement_text_content_by_id(announcement_id, text_content, Utc::now(), state).await;
+    }
+    if is_pinned.is_some() {
+        set_pool_announcement_is_pinned_by_id(announcement_id, is_pinned.unwrap(), state).await;
+    }
+
+    let msg = format!("Announcement with id \"{announcement_id}\" was updated");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+pub async fn user_delete_pool_announcement(pool_id : &str, announcement_id : &str, state : &AppState) -> ApiResponse {
+    let announcement_opt = get_pool_announcement_by_id(announcement_id, state).await;
+    if announcement_opt.is_none_or(|a| {a.pool_id() != pool_id}) {
+        let err_msg = format!("Announcement with id \"{announcement_id}\" not found in pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    delete_pool_announcement_by_id(announcement_id, state).await;
+    let msg = format!("Announcement with id \"{announcement_id}\" was deleted");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+const POOL_QUESTION_TEXT_MAX_LEN : usize = 500;
+const MEMBER_ANSWER_TEXT_MAX_LEN : usize = 1000;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserQuestionAnswer {
+    pub question_id : String,
+    pub answer : String
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserPoolQuestionResponse {
+    pub id : String,
+    pub text_content : String,
+    pub is_required : bool,
+    pub position : u64
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct UserMemberAnswerResponse {
+    pub question_id : String,
+    pub question : String,
+    pub is_required : bool,
+    pub answer : String
+}
+
+async fn get_pool_questions_response(pool_id : &str, state : &AppState) -> Vec<UserPoolQuestionResponse> {
+    let mut questions = get_pool_questions_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    questions.sort_by_key(|q| {q.position()});
+    return questions.iter().map(|q| {UserPoolQuestionResponse {
+        id: String::from(q.id()),
+        text_content: String::from(q.text_content()),
+        is_required: q.is_required(),
+        position: q.position()
+    }}).collect();
+}
+
+// unanswered optional questions are listed too, so the santa sees what was left blank
+async fn get_member_answers_response(pool_id : &str, account_id : &str, state : &AppState) -> Vec<UserMemberAnswerResponse> {
+    let mut questions = get_pool_questions_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    questions.sort_by_key(|q| {q.position()});
+    let answers = get_member_answers_by_pool_and_account_ids(pool_id, account_id, state).await.unwrap_or(vec![]);
+    return questions.iter().map(|q| {
+        let answer = answers.iter().find(|a| {a.question_id() == q.id()}).map(|a| {String::from(a.text_content())}).unwrap_or_default();
+        UserMemberAnswerResponse {
+            question_id: String::from(q.id()),
+            question: String::from(q.text_content()),
+            is_required: q.is_required(),
+            answer: answer
+        }
+    }).collect();
+}
+
+fn questionnaire_answers_error<Q: IPoolQuestion>(questions : &Vec<Q>, answers : &Vec<UserQuestionAnswer>) -> Option<String> {
+    for (i, answer) in answers.iter().enumerate() {
+        let question_id = answer.question_id.as_str();
+        if !questions.iter().any(|q| {q.id() == question_id}) {
+            return Some(format!("Question with id \"{question_id}\" not found in the pool questionnaire"));
+        }
+        if answers.iter().skip(i + 1).any(|a| {a.question_id == question_id}) {
+            return Some(format!("Question with id \"{question_id}\" is answered more than once"));
+        }
+        if answer.answer.trim().chars().count() > MEMBER_ANSWER_TEXT_MAX_LEN {
+            return Some(format!("Answer to question with id \"{question_id}\" can not be longer than {MEMBER_ANSWER_TEXT_MAX_LEN} characters"));
+        }
+    }
+    for question in questions.iter().filter(|q| {q.is_required()}) {
+        let is_answered = answers.iter().any(|a| {a.question_id == question.id() && !a.answer.trim().is_empty()});
+        if !is_answered {
+            let question_text = question.text_content();
+            return Some(format!("Question \"{question_text}\" is required"));
+        }
+    }
+    return None;
+}
+
+// answers replace the previous ones as a whole, empty answers are not stored
+async fn save_member_answers(pool_id : &str, account_id : &str, answers : &Vec<UserQuestionAnswer>, state : &AppState) -> () {
+    delete_member_answers_by_pool_and_account_ids(pool_id, account_id, state).await;
+    for answer in answers.iter().filter(|a| {!a.answer.trim().is_empty()}) {
+        let answer_id = new_id_safe(is_member_answer_already_exists_by_id, state).await;
+        create_member_answer(answer_id.as_str(), pool_id, account_id, answer.question_id.as_str(), answer.answer.trim(), state).await;
+    }
+}
+
+pub async fn user_get_pool_questions(pool_id : &str, state : &AppState) -> ApiResponse {
+    let pool_exists = is_pool_already_exists_by_id(pool_id, state).await;
+    if pool_exists.is_none_or(|b| {!b}) {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let result = get_pool_questions_response(pool_id, state).await;
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+// questions can be changed until the draw, members who joined earlier update their answers themselves
+pub async fn user_add_pool_question(pool_id : &str, text_content : &str, is_required : bool, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool_state = pool_opt.unwrap().state();
+    if PoolState::Created != pool_state && PoolState::Open != pool_state {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow changing the questionnaire. It can be changed only before the draw");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let text_content = text_content.trim();
+    if text_content.is_empty() {
+        let err_msg = format!("Question is empty");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    if text_content.chars().count() > POOL_QUESTION_TEXT_MAX_LEN {
+        let err_msg = format!("Question can not be longer than {POOL_QUESTION_TEXT_MAX_LEN} characters");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let questions = get_pool_questions_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let position = questions.iter().map(|q| {q.position() + 1}).max().unwrap_or(0);
+    let question_id = new_id_safe(is_pool_question_already_exists_by_id, state).await;
+    create_pool_question(question_id.as_str(), pool_id, text_content, is_required, position, state).await;
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(question_id).unwrap());
+}
+
+pub async fn user_delete_pool_question(pool_id : &str, question_id : &str, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool_state = pool_opt.unwrap().state();
+    if PoolState::Created != pool_state && PoolState::Open != pool_state {
+        let err_msg = format!("State of pool with id \"{pool_id}\" does not allow changing the questionnaire. It can be changed only before the draw");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let question_opt = get_pool_question_by_id(question_id, state).await;
+    if question_opt.is_none_or(|q| {q.pool_id() != pool_id}) {
+        let err_msg = format!("Question with id \"{question_id}\" not found in pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let db_service = SQLiteDbService::new(state);
+    db_service.delete_many_by_prop("member_answers", "question_id", vec![question_id]).await;
+    delete_pool_question_by_id(question_id, state).await;
+    let msg = format!("Question with id \"{question_id}\" was deleted");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+pub async fn user_get_my_answers_in_pool(pool_id : &str, account_id : &str, state : &AppState) -> ApiResponse {
+    let is_member = is_member_already_exists_by_pool_and_account_ids(pool_id, account_id, state).await;
+    let is_waitlisted = get_waitlist_entry_by_pool_and_account_ids(pool_id, account_id, state).await.is_some();
+    if !is_member && !is_waitlisted {
+        let err_msg = format!("User with account id \"{account_id}\" is not a member of pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let result = get_member_answers_response(pool_id, account_id, state).await;
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(result).unwrap());
+}
+
+pub async fn user_set_my_answers_in_pool(pool_id : &str, account_id : &str, answers : &Vec<UserQuestionAnswer>, state : &AppState) -> ApiResponse {
+    let pool_opt = get_pool_by_id(pool_id, state).await;
+    if pool_opt.is_none() {
+        let err_msg = format!("Pool with id \"{pool_id}\" not found");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let pool_state = pool_opt.unwrap().state();
+    if PoolState::Ended == pool_state || PoolState::Archived == pool_state {
+        let err_msg = format!("Pool with id \"{pool_id}\" already ended");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+    let is_member = is_member_already_exists_by_pool_and_account_ids(pool_id, account_id, state).await;
+    let is_waitlisted = get_waitlist_entry_by_pool_and_account_ids(pool_id, account_id, state).await.is_some();
+    if !is_member && !is_waitlisted {
+        let err_msg = format!("User with account id \"{account_id}\" is not a member of pool with id \"{pool_id}\"");
+        return ApiResponse::error_from_str(err_msg.as_str());
+    }
+
+    let questions = get_pool_questions_by_pool_id(pool_id, state).await.unwrap_or(vec![]);
+    let answers_error = questionnaire_answers_error(&questions, answers);
+    if answers_error.is_some() {
+        return ApiResponse::error_from_str(answers_error.unwrap().as_str());
+    }
+    save_member_answers(pool_id, account_id, answers, state).await;
+    let msg = format!("Answers of user with account id \"{account_id}\" in pool with id \"{pool_id}\" were saved");
+    return ApiResponse::new(ApiResponseStatus::OK, serde_json::to_value(msg).unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use crate::santa::data_model::implementations::{PoolQuestion, StageDeadline};
+
+    use super::*;
+
+    fn deadline(room_state : RoomState, days_from_now : i64, now : DateTime<Utc>) -> StageDeadline {
+        return StageDeadline::new("", "", room_state, now + TimeDelta::days(days_from_now));
+    }
+
+    #[test]
+    fn no_missed_deadline_before_any_passes() {
+        let now = Utc::now();
+        let deadlines = vec![deadline(RoomState::BuyingAGift, 1, now), deadline(RoomState::RecipientTookTheGift, 10, now)];
+        assert!(find_missed_deadline(RoomState::ChoosingAGift, &deadlines, now).is_none());
+    }
+
+    #[test]
+    fn reached_stage_is_not_missed() {
+        let now = Utc::now();
+        let deadlines = vec![deadline(RoomState::BuyingAGift, -1, now)];
+        assert!(find_missed_deadline(RoomState::BuyingAGift, &deadlines, now).is_none());
+        assert!(find_missed_deadline(RoomState::GiftInAWayToRecipient, &deadlines, now).is_none());
+    }
+
+    #[test]
+    fn earliest_missed_stage_is_reported() {
+        let now = Utc::now();
+        let deadlines = vec![
+            deadline(RoomState::RecipientTookTheGift, -1, now),
+            deadline(RoomState::MailerSendGiftToRecipient, -3, now),
+            deadline(RoomState::BuyingAGift, -5, now)
+        ];
+        let missed = find_missed_deadline(RoomState::BuyingAGift, &deadlines, now).unwrap();
+        assert_eq!(missed.room_state(), RoomState::MailerSendGiftToRecipient);
+    }
+
+    #[test]
+    fn deadline_is_missed_only_after_it_passes() {
+        let now = Utc::now();
+        let deadlines = vec![StageDeadline::new("", "", RoomState::BuyingAGift, now)];
+        assert!(find_missed_deadline(RoomState::ChoosingAGift, &deadlines, now).is_none());
+        assert!(find_missed_deadline(RoomState::ChoosingAGift, &deadlines, now + TimeDelta::seconds(1)).is_some());
+    }
+
+    #[test]
+    fn empty_or_partial_schedule_is_valid() {
+        let now = Utc::now();
+        assert!(pool_schedule_error(None, None, None).is_none());
+        assert!(pool_schedule_error(None, Some(now), None).is_none());
+        assert!(pool_schedule_error(Some(now), None, Some(now + TimeDelta::days(1))).is_none());
+    }
+
+    #[test]
+    fn schedule_in_stage_order_is_valid() {
+        let now = Utc::now();
+        assert!(pool_schedule_error(Some(now), Some(now + TimeDelta::days(1)), Some(now + TimeDelta::days(2))).is_none());
+    }
+
+    #[test]
+    fn schedule_out_of_stage_order_is_rejected() {
+        let now = Utc::now();
+        let err = pool_schedule_error(Some(now), Some(now - TimeDelta::days(1)), None).unwrap();
+        assert_eq!(err, "Scheduled time \"draw_at\" must be later than \"open_at\"");
+        let err = pool_schedule_error(Some(now + TimeDelta::days(1)), None, Some(now)).unwrap();
+        assert_eq!(err, "Scheduled time \"end_at\" must be later than \"open_at\"");
+    }
+
+    #[test]
+    fn schedule_with_equal_times_is_rejected() {
+        let now = Utc::now();
+        assert!(pool_schedule_error(None, Some(now), Some(now)).is_some());
+    }
+
+    #[test]
+    fn valid_price_range_is_accepted() {
+        assert!(pool_price_range_error(0, 10, "EUR").is_none());
+        assert!(pool_price_range_error(10, 10, "RUB").is_none());
+    }
+
+    #[test]
+    fn unknown_currency_is_rejected() {
+        assert_eq!(pool_price_range_error(1, 10, "ABC").unwrap(), "Currency \"ABC\" is not a valid ISO 4217 code");
+        assert!(pool_price_range_error(1, 10, "eur").is_some());
+        assert!(pool_price_range_error(1, 10, "").is_some());
+    }
+
+    #[test]
+    fn zero_maximum_price_is_rejected() {
+        assert_eq!(pool_price_range_error(0, 0, "USD").unwrap(), "Maximum price must be greater than zero");
+    }
+
+    #[test]
+    fn minimum_above_maximum_price_is_rejected() {
+        assert_eq!(pool_price_range_error(20, 10, "USD").unwrap(), "Minimum price 20 USD can not be greater than maximum price 10 USD");
+    }
+
+    fn questions() -> Vec<PoolQuestion> {
+        return vec![PoolQuestion::new("size", "", "Clothes size?", true, 0), PoolQuestion::new("allergy", "", "Any allergies?", false, 1)];
+    }
+
+    fn answer(question_id : &str, answer : &str) -> UserQuestionAnswer {
+        return UserQuestionAnswer { question_id: String::from(question_id), answer: String::from(answer) };
+    }
+
+    #[test]
+    fn required_answers_are_enough() {
+        assert!(questionnaire_answers_error(&questions(), &vec![answer("size", "M")]).is_none());
+        assert!(questionnaire_answers_error(&questions(), &vec![answer("allergy", ""), answer("size", "M")]).is_none());
+        assert!(questionnaire_answers_error(&Vec::<PoolQuestion>::new(), &vec![]).is_none());
+    }
+
+    #[test]
+    fn missing_or_blank_required_answer_is_rejected() {
+        assert_eq!(questionnaire_answers_error(&questions(), &vec![]).unwrap(), "Question \"Clothes size?\" is required");
+        assert!(questionnaire_answers_error(&questions(), &vec![answer("size", "   ")]).is_some());
+    }
+
+    #[test]
+    fn answer_to_unknown_question_is_rejected() {
+        let err = questionnaire_answers_error(&questions(), &vec![answer("size", "M"), answer("color", "red")]).unwrap();
+        assert_eq!(err, "Question with id \"color\" not found in the pool questionnaire");
+    }
+
+    #[test]
+    fn repeated_answer_is_rejected() {
+        let err = questionnaire_answers_error(&questions(), &vec![answer("size", "M"), answer("size", "L")]).unwrap();
+        assert_eq!(err, "Question with id \"size\" is answered more than once");
+    }
+
+    #[test]
+    fn too_long_answer_is_rejected() {
+        let long_answer = "a".repeat(MEMBER_ANSWER_TEXT_MAX_LEN + 1);
+        assert!(questionnaire_answers_error(&questions(), &vec![answer("size", long_answer.as_str())]).is_some());
+        let padded_answer = format!("  {}  ", "a".repeat(MEMBER_ANSWER_TEXT_MAX_LEN));
+        assert!(questionnaire_answers_error(&questions(), &vec![answer("size", padded_answer.as_str())]).is_none());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,54 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::services::{IDbService, SQLiteDbService}, santa::data_model::{implementations::Shipment, traits::IShipment}, AppState};
+
+// an empty expected_delivery_date means the mailer did not set it
+pub fn row_to_shipment(row : &SqliteRow) -> Shipment {
+    let id : &str = row.get("id");
+    let room_id : &str = row.get("room_id");
+    let pool_id : &str = row.get("pool_id");
+    let carrier_name : &str = row.get("carrier_name");
+    let tracking_number : &str = row.get("tracking_number");
+    let expected_delivery_date_str : &str = row.get("expected_delivery_date");
+    let expected_delivery_date = NaiveDate::from_str(expected_delivery_date_str).ok();
+    let last_update_date_str : &str = row.get("last_update_date");
+    let last_update_date : DateTime<Utc> = DateTime::from_str(last_update_date_str).unwrap();
+    return Shipment::new(id, room_id, pool_id, carrier_name, tracking_number, expected_delivery_date, last_update_date);
+}
+
+pub async fn get_shipment_by_room_id(room_id : &str, state : &AppState) -> Option<impl IShipment> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_one_by_prop("shipments", "room_id", room_id, row_to_shipment).await;
+}
+
+pub async fn is_shipment_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("shipments", "id", id).await;
+}
+
+pub async fn create_shipment(id : &str, room_id : &str, pool_id : &str, carrier_name : &str, tracking_number : &str, expected_delivery_date : Option<NaiveDate>, last_update_date : DateTime<Utc>, state : &AppState) -> () {
+    let expected_delivery_date_string = expected_delivery_date.map(|d| {d.to_string()}).unwrap_or_default();
+    let expected_delivery_date_str = expected_delivery_date_string.as_str();
+    let last_update_date_string = last_update_date.to_rfc3339();
+    let last_update_date_str = last_update_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("shipments",
+    vec!["id", "room_id", "pool_id", "carrier_name", "tracking_number", "expected_delivery_date", "last_update_date"],
+    vec![vec![id, room_id, pool_id, carrier_name, tracking_number, expected_delivery_date_str, last_update_date_str]]).await;
+}
+
+pub async fn set_shipment_details_by_room_id(room_id : &str, carrier_name : &str, tracking_number : &str, expected_delivery_date : Option<NaiveDate>, last_update_date : DateTime<Utc>, state : &AppState) -> () {
+    let expected_delivery_date_string = expected_delivery_date.map(|d| {d.to_string()}).unwrap_or_default();
+    let expected_delivery_date_str = expected_delivery_date_string.as_str();
+    let last_update_date_string = last_update_date.to_rfc3339();
+    let last_update_date_str = last_update_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("shipments", "room_id", room_id,
+    vec!["carrier_name", "tracking_number", "expected_delivery_date", "last_update_date"],
+    vec![carrier_name, tracking_number, expected_delivery_date_str, last_update_date_str]).await;
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::services::{IDbService, SQLiteDbService}, santa::data_model::{enums::RoomState, implementations::StageDeadline, traits::IStageDeadline}, AppState};
+
+pub fn row_to_stage_deadline(row : &SqliteRow) -> StageDeadline {
+    let id : &str = row.get("id");
+    let pool_id : &str = row.get("pool_id");
+    let room_state_num : u8 = row.get("room_state");
+    let room_state = RoomState::try_from(usize::from(room_state_num)).unwrap();
+    let deadline_str : &str = row.get("deadline");
+    let deadline : DateTime<Utc> = DateTime::from_str(deadline_str).unwrap();
+    return StageDeadline::new(id, pool_id, room_state, deadline);
+}
+
+pub async fn get_stage_deadlines_by_pool_id(pool_id : &str, state : &AppState) -> Option<Vec<impl IStageDeadline>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("stage_deadlines", "pool_id", vec![pool_id], row_to_stage_deadline).await;
+}
+
+pub async fn is_stage_deadline_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("stage_deadlines", "id", id).await;
+}
+
+pub async fn create_stage_deadline(id : &str, pool_id : &str, room_state : RoomState, deadline : DateTime<Utc>, state : &AppState) -> () {
+    let room_state_num = room_state as usize;
+    let room_state_string = room_state_num.to_string();
+    let room_state_str = room_state_string.as_str();
+    let deadline_string = deadline.to_rfc3339();
+    let deadline_str = deadline_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("stage_deadlines",
+    vec!["id", "pool_id", "room_state", "deadline"],
+    vec![vec![id, pool_id, room_state_str, deadline_str]]).await;
+}
+
+pub async fn delete_stage_deadlines_by_pool_id(pool_id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    db_service.delete_many_by_prop("stage_deadlines", "pool_id", vec![pool_id]).await;
+}
\ No newline at end of file
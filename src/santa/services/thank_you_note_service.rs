@@ -0,0 +1,51 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::services::{IDbService, SQLiteDbService}, santa::data_model::{implementations::ThankYouNote, traits::IThankYouNote}, AppState};
+
+pub fn row_to_thank_you_note(row : &SqliteRow) -> ThankYouNote {
+    let id : &str = row.get("id");
+    let room_id : &str = row.get("room_id");
+    let pool_id : &str = row.get("pool_id");
+    let text_content : &str = row.get("text_content");
+    let photo_type : &str = row.get("photo_type");
+    let photo : &str = row.get("photo");
+    let creation_date_str : &str = row.get("creation_date");
+    let creation_date : DateTime<Utc> = DateTime::from_str(creation_date_str).unwrap();
+    return ThankYouNote::new(id, room_id, pool_id, text_content, photo_type, photo, creation_date);
+}
+
+pub async fn get_thank_you_note_by_room_id(room_id : &str, state : &AppState) -> Option<impl IThankYouNote> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_one_by_prop("thank_you_notes", "room_id", room_id, row_to_thank_you_note).await;
+}
+
+pub async fn get_thank_you_notes_by_pool_id(pool_id : &str, state : &AppState) -> Option<Vec<impl IThankYouNote>> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.get_many_by_prop("thank_you_notes", "pool_id", vec![pool_id], row_to_thank_you_note).await;
+}
+
+pub async fn is_thank_you_note_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("thank_you_notes", "id", id).await;
+}
+
+pub async fn create_thank_you_note(id : &str, room_id : &str, pool_id : &str, text_content : &str, photo_type : &str, photo : &str, creation_date : DateTime<Utc>, state : &AppState) -> () {
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("thank_you_notes",
+    vec!["id", "room_id", "pool_id", "text_content", "photo_type", "photo", "creation_date"],
+    vec![vec![id, room_id, pool_id, text_content, photo_type, photo, creation_date_str]]).await;
+}
+
+pub async fn set_thank_you_note_by_id(id : &str, text_content : &str, photo_type : &str, photo : &str, creation_date : DateTime<Utc>, state : &AppState) -> () {
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    db_service.update("thank_you_notes", "id", id, vec!["text_content", "photo_type", "photo", "creation_date"], vec![text_content, photo_type, photo, creation_date_str]).await;
+}
\ No newline at end of file
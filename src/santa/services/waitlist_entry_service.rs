@@ -0,0 +1,56 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use sqlx::{sqlite::SqliteRow, Row};
+
+use crate::{core::{functions::{get_one_item_from_command, render_query_template}, services::{IDbService, SQLiteDbService}}, santa::data_model::{implementations::WaitlistEntry, traits::IWaitlistEntry}, AppState};
+
+pub fn row_to_waitlist_entry(row : &SqliteRow) -> WaitlistEntry {
+    let id : &str = row.get("id");
+    let pool_id : &str = row.get("pool_id");
+    let account_id : &str = row.get("account_id");
+    let wishlist : &str = row.get("wishlist");
+    let creation_date_str : &str = row.get("creation_date");
+    let creation_date : DateTime<Utc> = DateTime::from_str(creation_date_str).unwrap();
+    return WaitlistEntry::new(id, pool_id, account_id, wishlist, creation_date);
+}
+
+// the earliest entry comes first, it is the next one to become a member
+pub async fn get_waitlist_entries_by_pool_id(pool_id : &str, state : &AppState) -> Option<Vec<impl IWaitlistEntry>> {
+    let db_service = SQLiteDbService::new(state);
+    let entries_opt = db_service.get_many_by_prop("waitlist_entries", "pool_id", vec![pool_id], row_to_waitlist_entry).await;
+    return entries_opt.map(|mut entries| {
+        entries.sort_by_key(|e| {e.creation_date()});
+        entries
+    });
+}
+
+pub async fn get_waitlist_entry_by_pool_and_account_ids(pool_id : &str, account_id : &str, state : &AppState) -> Option<impl IWaitlistEntry> {
+    const GET_WAITLIST_ENTRY_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE : &str = "database_scripts/waitlist_entry/get_waitlist_entry_by_pool_and_account_ids.sql";
+    let mut context = tera::Context::new();
+    context.insert("pool_id", &pool_id);
+    context.insert("account_id", &account_id);
+
+    let command = render_query_template(GET_WAITLIST_ENTRY_BY_POOL_AND_ACCOUNT_IDS_TEMPLATE, &context, &state).await;
+    return get_one_item_from_command(command.as_str(), state, row_to_waitlist_entry).await;
+}
+
+pub async fn is_waitlist_entry_already_exists_by_id(id : &str, state : &AppState) -> Option<bool> {
+    let db_service = SQLiteDbService::new(state);
+    return db_service.exists_by_prop("waitlist_entries", "id", id).await;
+}
+
+pub async fn create_waitlist_entry(id : &str, pool_id : &str, account_id : &str, wishlist : &str, creation_date : DateTime<Utc>, state : &AppState) -> () {
+    let creation_date_string = creation_date.to_rfc3339();
+    let creation_date_str = creation_date_string.as_str();
+
+    let db_service = SQLiteDbService::new(state);
+    let _ = db_service.insert("waitlist_entries",
+    vec!["id", "pool_id", "account_id", "wishlist", "creation_date"],
+    vec![vec![id, pool_id, account_id, wishlist, creation_date_str]]).await;
+}
+
+pub async fn delete_waitlist_entry_by_id(id : &str, state : &AppState) -> () {
+    let db_service = SQLiteDbService::new(state);
+    db_service.delete_one_by_prop("waitlist_entries", "id", id).await;
+}
\ No newline at end of file